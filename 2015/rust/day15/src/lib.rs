@@ -0,0 +1,180 @@
+use advent_support::{parse_each_line, ParseError, Solution, Span};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, char, digit1, space0};
+use nom::combinator::{map_res, opt, recognize};
+use nom::error::context;
+use nom::sequence::{delimited, preceded, terminated};
+use nom::IResult;
+
+/// A combinator that takes a parser `inner` and produces a parser that also consumes both leading and
+/// trailing whitespace, returning the output of `inner`.
+fn ws<'a, F, O>(inner: F) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, O, ParseError>
+where
+    F: FnMut(Span<'a>) -> IResult<Span<'a>, O, ParseError>,
+{
+    delimited(space0, inner, space0)
+}
+
+fn alpha(input: Span) -> IResult<Span, Span, ParseError> {
+    context("an ingredient name", alpha1)(input)
+}
+
+fn number(input: Span) -> IResult<Span, i64, ParseError> {
+    context(
+        "a number",
+        map_res(recognize(preceded(opt(tag("-")), digit1)), |s: Span| {
+            s.fragment().parse()
+        }),
+    )(input)
+}
+
+/// A `name value` pair such as `capacity -2`.
+fn property<'a>(name: &'static str) -> impl FnMut(Span<'a>) -> IResult<Span<'a>, i64, ParseError> {
+    preceded(ws(context(name, tag(name))), ws(number))
+}
+
+#[derive(Debug)]
+struct Ingredient {
+    capacity: i64,
+    durability: i64,
+    flavor: i64,
+    texture: i64,
+    calories: i64,
+}
+
+impl Ingredient {
+    fn parse(input: Span) -> IResult<Span, Self, ParseError> {
+        let (input, _) = terminated(alpha, char(':'))(input)?;
+        let (input, capacity) = terminated(property("capacity"), char(','))(input)?;
+        let (input, durability) = terminated(property("durability"), char(','))(input)?;
+        let (input, flavor) = terminated(property("flavor"), char(','))(input)?;
+        let (input, texture) = terminated(property("texture"), char(','))(input)?;
+        let (input, calories) = property("calories")(input)?;
+
+        Ok((
+            input,
+            Self {
+                capacity,
+                durability,
+                flavor,
+                texture,
+                calories,
+            },
+        ))
+    }
+
+    fn is_valid(&self) -> bool {
+        self.capacity > 0
+            && self.durability > 0
+            && self.flavor > 0
+            && self.texture > 0
+            && self.calories > 0
+    }
+}
+
+fn score(ingredients: &[Ingredient], spoons: &[i64]) -> Option<(i64, i64)> {
+    let computed = ingredients
+        .iter()
+        .zip(spoons)
+        .map(|(ingredient, number)| Ingredient {
+            capacity: ingredient.capacity * number,
+            durability: ingredient.durability * number,
+            flavor: ingredient.flavor * number,
+            texture: ingredient.texture * number,
+            calories: ingredient.calories * number,
+        })
+        .fold(
+            Ingredient {
+                capacity: 0,
+                durability: 0,
+                flavor: 0,
+                texture: 0,
+                calories: 0,
+            },
+            |acc, ingredient| Ingredient {
+                capacity: acc.capacity + ingredient.capacity,
+                durability: acc.durability + ingredient.durability,
+                flavor: acc.flavor + ingredient.flavor,
+                texture: acc.texture + ingredient.texture,
+                calories: acc.calories + ingredient.calories,
+            },
+        );
+
+    if computed.is_valid() {
+        Some((
+            computed.capacity * computed.durability * computed.texture * computed.flavor,
+            computed.calories,
+        ))
+    } else {
+        None
+    }
+}
+
+fn spoon_permutations(ingredients: &[Ingredient]) -> Vec<Vec<i64>> {
+    (0..ingredients.len())
+        .map(|_| 0..100)
+        .multi_cartesian_product()
+        .filter(|items| items.iter().sum::<i64>() == 100)
+        .collect()
+}
+
+fn max_ignoring_calories(ingredients: &[Ingredient]) -> Option<i64> {
+    spoon_permutations(ingredients)
+        .iter()
+        .filter_map(|spoons| score(ingredients, spoons).map(|(value, _)| value))
+        .max()
+}
+
+fn max_with_500_calories(ingredients: &[Ingredient]) -> Option<i64> {
+    spoon_permutations(ingredients)
+        .iter()
+        .filter_map(|spoons| {
+            score(ingredients, spoons)
+                .and_then(|(value, calories)| if calories == 500 { Some(value) } else { None })
+        })
+        .max()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let ingredients = parse_each_line(input, Ingredient::parse)?;
+        max_ignoring_calories(&ingredients)
+            .map(|value| value.to_string())
+            .ok_or_else(|| anyhow!("no valid recipe"))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let ingredients = parse_each_line(input, Ingredient::parse)?;
+        max_with_500_calories(&ingredients)
+            .map(|value| value.to_string())
+            .ok_or_else(|| anyhow!("no valid recipe with 500 calories"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3
+";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day15.part1(EXAMPLE).unwrap(), "62842880");
+        assert_eq!(Day15.part2(EXAMPLE).unwrap(), "57600000");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = EXAMPLE.replace("texture -1", "texture x");
+        let error = parse_each_line(&input, Ingredient::parse).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 56));
+        assert_eq!(error.expected(), "a number");
+    }
+}
@@ -0,0 +1,70 @@
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+const LITERS: u64 = 150;
+
+fn parse_containers(input: &str) -> Result<Vec<u64>> {
+    advent_support::parse_lines(input)
+}
+
+fn number_of_exact_matches(containers: &[u64], liters: u64) -> usize {
+    containers
+        .iter()
+        .powerset()
+        .map(|x| x.into_iter().sum())
+        .filter(|x: &u64| *x == liters)
+        .count()
+}
+
+fn number_of_smallest_matches(containers: &[u64], liters: u64) -> Option<usize> {
+    let mut iter = containers
+        .iter()
+        .powerset()
+        .filter_map(|x| {
+            if x.clone().into_iter().sum::<u64>() == liters {
+                Some(x)
+            } else {
+                None
+            }
+        })
+        .sorted_by_key(|x| x.len());
+    let first_item = iter.next()?;
+    let mut count = 1;
+    for item in iter {
+        if item.len() == first_item.len() {
+            count += 1;
+        } else {
+            break;
+        }
+    }
+    Some(count)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let containers = parse_containers(input)?;
+        Ok(number_of_exact_matches(&containers, LITERS).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let containers = parse_containers(input)?;
+        number_of_smallest_matches(&containers, LITERS)
+            .map(|count| count.to_string())
+            .ok_or_else(|| anyhow!("no combination holds {} liters", LITERS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let containers = [20, 15, 10, 5, 5];
+        assert_eq!(number_of_exact_matches(&containers, 25), 4);
+        assert_eq!(number_of_smallest_matches(&containers, 25), Some(3));
+    }
+}
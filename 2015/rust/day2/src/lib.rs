@@ -0,0 +1,67 @@
+use advent_support::Solution;
+use anyhow::Result;
+
+fn paper_needed(sides: &[i32]) -> i64 {
+    let length: i64 = sides[0].into();
+    let width: i64 = sides[1].into();
+    let height: i64 = sides[2].into();
+
+    let smallest: i64 = length * width;
+
+    (2 * length * width) + (2 * width * height) + (2 * height * length) + smallest
+}
+
+fn ribbon_needed(sides: &[i32]) -> i64 {
+    let length: i64 = sides[0].into();
+    let width: i64 = sides[1].into();
+    let height: i64 = sides[2].into();
+
+    let mut pairs = [(length, width), (length, height), (width, height)];
+    pairs.sort();
+
+    (length * width * height) + (2 * pairs[0].0) + (2 * pairs[0].1)
+}
+
+fn parse_sides(input: &[String]) -> Result<Vec<Vec<i32>>> {
+    input
+        .iter()
+        .map(|line| {
+            let mut sides = line
+                .split('x')
+                .map(|v| v.parse::<i32>())
+                .collect::<Result<Vec<i32>, _>>()?;
+            sides.sort();
+            Ok(sides)
+        })
+        .collect()
+}
+
+fn part_one(input: &[String]) -> Result<i64> {
+    let total_paper_needed: i64 = parse_sides(input)?
+        .iter()
+        .map(|sides| paper_needed(sides))
+        .sum();
+    Ok(total_paper_needed)
+}
+
+fn part_two(input: &[String]) -> Result<i64> {
+    let total_ribbon_needed: i64 = parse_sides(input)?
+        .iter()
+        .map(|sides| ribbon_needed(sides))
+        .sum();
+    Ok(total_ribbon_needed)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let lines = advent_support::parse_lines::<String>(input)?;
+        Ok(part_one(&lines)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let lines = advent_support::parse_lines::<String>(input)?;
+        Ok(part_two(&lines)?.to_string())
+    }
+}
@@ -0,0 +1,564 @@
+use std::fmt;
+use std::fmt::Display;
+use std::iter;
+
+use advent_support::{Battle, Combat, Combatant, ParseError, Side, Solution, Step, Verdict};
+use anyhow::{anyhow, bail, Context, Result};
+use itertools::Itertools;
+
+/// The shop as the puzzle lists it.
+const PUZZLE_SHOP: &str = include_str!("../shop.txt");
+
+/// The player's hit points, which the puzzle fixes.
+pub const PLAYER_HIT_POINTS: u32 = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub name: String,
+    pub cost: u32,
+    pub damage: u32,
+    pub armor: u32,
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}, cost: {}, damage: {}, armor: {}",
+            self.name, self.cost, self.damage, self.armor
+        )
+    }
+}
+
+/// One table of the shop, like `Weapons`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub name: String,
+    pub items: Vec<Item>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shop {
+    pub sections: Vec<Section>,
+}
+
+/// Each whitespace separated field of `line`, with the byte offset it starts at.
+fn fields(line: &str) -> Vec<(usize, &str)> {
+    let mut fields = Vec::new();
+    let mut start = None;
+    for (index, c) in line.char_indices().chain(iter::once((line.len(), ' '))) {
+        if !c.is_whitespace() {
+            start.get_or_insert(index);
+        } else if let Some(start) = start.take() {
+            fields.push((start, &line[start..index]));
+        }
+    }
+    fields
+}
+
+impl Shop {
+    /// Read tables in the puzzle's format: a `Name:  Cost  Damage  Armor` header, then one item a
+    /// line with the three numbers last, and a blank line between tables.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut sections: Vec<Section> = Vec::new();
+        let mut in_section = false;
+
+        for (number, line) in (1..).zip(text.lines()) {
+            let fields = fields(line);
+            if fields.is_empty() {
+                in_section = false;
+                continue;
+            }
+
+            let header = fields[0].1.strip_suffix(':');
+            if let (Some(name), false) = (header, in_section) {
+                let columns: Vec<&str> = fields[1..].iter().map(|(_, field)| *field).collect();
+                if columns != ["Cost", "Damage", "Armor"] {
+                    let column = fields.get(1).map_or(line.len(), |(start, _)| *start) + 1;
+                    return Err(ParseError::in_input(
+                        text,
+                        number,
+                        column,
+                        "`Cost  Damage  Armor`",
+                    ));
+                }
+                sections.push(Section {
+                    name: name.to_string(),
+                    items: Vec::new(),
+                });
+                in_section = true;
+                continue;
+            }
+            if !in_section {
+                return Err(ParseError::in_input(
+                    text,
+                    number,
+                    1,
+                    "a table header like `Weapons:  Cost  Damage  Armor`",
+                ));
+            }
+
+            if fields.len() < 4 {
+                return Err(ParseError::in_input(
+                    text,
+                    number,
+                    line.len() + 1,
+                    "an item name followed by its cost, damage and armor",
+                ));
+            }
+            let (name, numbers) = fields.split_at(fields.len() - 3);
+            let mut values = [0; 3];
+            for (value, (start, field)) in values.iter_mut().zip(numbers) {
+                *value = field
+                    .parse()
+                    .map_err(|_| ParseError::in_input(text, number, start + 1, "a number"))?;
+            }
+            let [cost, damage, armor] = values;
+            sections.last_mut().unwrap().items.push(Item {
+                name: name.iter().map(|(_, field)| *field).join(" "),
+                cost,
+                damage,
+                armor,
+            });
+        }
+
+        Ok(Self { sections })
+    }
+
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    /// Every way of filling `slots` with items from the shop, buying no item twice and nothing
+    /// from a section without a slot.
+    pub fn loadouts(&self, slots: &[Slot]) -> Result<Vec<Loadout<'_>>> {
+        let mut loadouts = vec![Loadout::default()];
+        for slot in slots {
+            let section = self
+                .section(&slot.section)
+                .ok_or_else(|| anyhow!("the shop has no {}", slot.section))?;
+            if slot.min > slot.max || slot.min > section.items.len() {
+                bail!(
+                    "cannot buy {} to {} of the {} {}",
+                    slot.min,
+                    slot.max,
+                    section.items.len(),
+                    section.name
+                );
+            }
+            let choices: Vec<Vec<&Item>> = (slot.min..=slot.max.min(section.items.len()))
+                .flat_map(|count| section.items.iter().combinations(count))
+                .collect();
+            loadouts = loadouts
+                .iter()
+                .flat_map(|loadout| {
+                    choices.iter().map(move |choice| Loadout {
+                        items: loadout.items.iter().chain(choice).copied().collect(),
+                    })
+                })
+                .collect();
+        }
+        Ok(loadouts)
+    }
+}
+
+impl Default for Shop {
+    fn default() -> Self {
+        Self::parse(PUZZLE_SHOP).expect("the puzzle shop parses")
+    }
+}
+
+/// How many items to buy from one section of the shop.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Slot {
+    pub section: String,
+    pub min: usize,
+    pub max: usize,
+}
+
+impl Slot {
+    pub fn new(section: &str, min: usize, max: usize) -> Self {
+        Self {
+            section: section.to_string(),
+            min,
+            max,
+        }
+    }
+}
+
+/// The puzzle's rules: exactly one weapon, armor is optional and up to two rings.
+pub fn puzzle_slots() -> Vec<Slot> {
+    vec![
+        Slot::new("Weapons", 1, 1),
+        Slot::new("Armor", 0, 1),
+        Slot::new("Rings", 0, 2),
+    ]
+}
+
+/// Items bought together.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Loadout<'a> {
+    pub items: Vec<&'a Item>,
+}
+
+impl<'a> Loadout<'a> {
+    pub fn cost(&self) -> u32 {
+        self.items.iter().map(|item| item.cost).sum()
+    }
+
+    /// A fighter with `hit_points` wearing everything in the loadout.
+    pub fn stats(&self, hit_points: u32) -> Combatant {
+        Combatant {
+            hit_points,
+            damage: self.items.iter().map(|item| item.damage).sum(),
+            armor: self.items.iter().map(|item| item.armor).sum(),
+            mana: 0,
+        }
+    }
+}
+
+impl<'a> Display for Loadout<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.items.iter().map(|item| &item.name).join(", "))
+    }
+}
+
+/// The rounds each side needs to finish the other off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    pub player_rounds: u32,
+    pub boss_rounds: u32,
+}
+
+impl Outcome {
+    /// The player attacks first, so a tie goes to them.
+    pub fn player_wins(&self) -> bool {
+        self.player_rounds <= self.boss_rounds
+    }
+
+    /// Rounds the player has to spare, negative when they lose.
+    pub fn margin(&self) -> i64 {
+        i64::from(self.boss_rounds) - i64::from(self.player_rounds)
+    }
+}
+
+/// Work out the fight from the stats alone.
+pub fn fight(player: &Combatant, boss: &Combatant) -> Outcome {
+    Outcome {
+        player_rounds: boss.hit_points.div_ceil(player.hit(boss)),
+        boss_rounds: player.hit_points.div_ceil(boss.hit(player)),
+    }
+}
+
+/// A fight of nothing but attacks, so the player has no choices to make.
+struct Brawl;
+
+impl Combat for Brawl {
+    type Status = ();
+    type Move = ();
+
+    fn player_move(&self, _choice: &(), battle: &mut Battle<()>) -> Verdict {
+        let Battle { player, boss, .. } = battle;
+        player.attack(boss);
+        Verdict::Continue
+    }
+}
+
+/// Play the fight out a turn at a time, returning whether the player wins.
+pub fn simulate(player: &Combatant, boss: &Combatant) -> bool {
+    let mut battle = Battle::new(*player, *boss);
+    loop {
+        match battle.round(&Brawl, &()) {
+            Verdict::Continue => {}
+            verdict => return verdict == Verdict::Win,
+        }
+    }
+}
+
+/// Play the fight out, describing each attack the way the puzzle does.
+pub fn battle_log(player: &Combatant, boss: &Combatant) -> String {
+    let mut log = String::new();
+    let mut battle = Battle::new(*player, *boss);
+    let mut describe = |side, step, before: &Battle<()>, after: &Battle<()>| {
+        if step != Step::Move {
+            return;
+        }
+        let (attacker, defender, names) = match side {
+            Side::Player => (&before.player, &after.boss, ("player", "boss")),
+            Side::Boss => (&before.boss, &after.player, ("boss", "player")),
+        };
+        let armor = defender.armor;
+        let damage = if attacker.damage > armor {
+            format!(
+                "{}-{} = {}",
+                attacker.damage,
+                armor,
+                attacker.damage - armor
+            )
+        } else {
+            "1".to_string()
+        };
+        log.push_str(&format!(
+            "The {} deals {} damage; the {} goes down to {} hit points.\n",
+            names.0, damage, names.1, defender.hit_points
+        ));
+    };
+    let verdict = loop {
+        match battle.round_watched(&Brawl, &(), &mut describe) {
+            Verdict::Continue => {}
+            verdict => break verdict,
+        }
+    };
+    let winner = if verdict == Verdict::Win {
+        "player"
+    } else {
+        "boss"
+    };
+    log.push_str(&format!("The {} wins.\n", winner));
+    log
+}
+
+/// The outcome of fighting `boss` in every loadout `slots` allow.
+pub fn fight_results<'a>(
+    shop: &'a Shop,
+    slots: &[Slot],
+    player_hit_points: u32,
+    boss: &Combatant,
+) -> Result<Vec<(Loadout<'a>, Outcome)>> {
+    Ok(shop
+        .loadouts(slots)?
+        .into_iter()
+        .map(|loadout| {
+            let outcome = fight(&loadout.stats(player_hit_points), boss);
+            (loadout, outcome)
+        })
+        .collect())
+}
+
+/// The loadouts no other is at least as cheap as and wins by at least as much, cheapest first.
+/// Each is the cheapest way to get its margin, so the first that wins is the cheapest win.
+pub fn pareto_frontier<'r, 'a>(
+    results: &'r [(Loadout<'a>, Outcome)],
+) -> Vec<&'r (Loadout<'a>, Outcome)> {
+    let mut sorted: Vec<_> = results.iter().collect();
+    sorted.sort_by_key(|(loadout, outcome)| (loadout.cost(), -outcome.margin()));
+
+    let mut frontier: Vec<&(Loadout, Outcome)> = Vec::new();
+    for result in sorted {
+        if frontier
+            .last()
+            .is_none_or(|(_, best)| result.1.margin() > best.margin())
+        {
+            frontier.push(result);
+        }
+    }
+    frontier
+}
+
+fn cheapest_win(results: &[(Loadout, Outcome)]) -> Option<u32> {
+    pareto_frontier(results)
+        .into_iter()
+        .find(|(_, outcome)| outcome.player_wins())
+        .map(|(loadout, _)| loadout.cost())
+}
+
+fn most_expensive_loss(results: &[(Loadout, Outcome)]) -> Option<u32> {
+    results
+        .iter()
+        .filter(|(_, outcome)| !outcome.player_wins())
+        .map(|(loadout, _)| loadout.cost())
+        .max()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let shop = Shop::default();
+        let results = fight_results(
+            &shop,
+            &puzzle_slots(),
+            PLAYER_HIT_POINTS,
+            &Combatant::parse(input).context("reading the boss")?,
+        )?;
+        cheapest_win(&results)
+            .map(|cost| cost.to_string())
+            .ok_or_else(|| anyhow!("no gear wins"))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let shop = Shop::default();
+        let results = fight_results(
+            &shop,
+            &puzzle_slots(),
+            PLAYER_HIT_POINTS,
+            &Combatant::parse(input).context("reading the boss")?,
+        )?;
+        most_expensive_loss(&results)
+            .map(|cost| cost.to_string())
+            .ok_or_else(|| anyhow!("no gear loses"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use super::*;
+
+    #[test]
+    fn test_fight() {
+        // The example from the puzzle.
+        let player = Combatant {
+            hit_points: 8,
+            damage: 5,
+            armor: 5,
+            mana: 0,
+        };
+        let boss = Combatant {
+            hit_points: 12,
+            damage: 7,
+            armor: 2,
+            mana: 0,
+        };
+        let outcome = fight(&player, &boss);
+        assert_eq!(
+            outcome,
+            Outcome {
+                player_rounds: 4,
+                boss_rounds: 4
+            }
+        );
+        assert!(outcome.player_wins());
+        assert!(simulate(&player, &boss));
+        assert_eq!(
+            battle_log(&player, &boss),
+            "The player deals 5-2 = 3 damage; the boss goes down to 9 hit points.
+The boss deals 7-5 = 2 damage; the player goes down to 6 hit points.
+The player deals 5-2 = 3 damage; the boss goes down to 6 hit points.
+The boss deals 7-5 = 2 damage; the player goes down to 4 hit points.
+The player deals 5-2 = 3 damage; the boss goes down to 3 hit points.
+The boss deals 7-5 = 2 damage; the player goes down to 2 hit points.
+The player deals 5-2 = 3 damage; the boss goes down to 0 hit points.
+The player wins.
+"
+        );
+    }
+
+    #[test]
+    fn test_shop() {
+        let shop = Shop::default();
+        let rings = shop.section("Rings").unwrap();
+        assert_eq!(rings.items.len(), 6);
+        assert_eq!(
+            rings.items[4],
+            Item {
+                name: "Defense +2".to_string(),
+                cost: 40,
+                damage: 0,
+                armor: 2
+            }
+        );
+
+        // 5 weapons, 6 choices of armor and 1 + 6 + 15 of rings.
+        assert_eq!(shop.loadouts(&puzzle_slots()).unwrap().len(), 5 * 6 * 22);
+        assert!(shop.loadouts(&[Slot::new("Potions", 0, 1)]).is_err());
+        assert!(shop.loadouts(&[Slot::new("Weapons", 6, 6)]).is_err());
+
+        let error =
+            Shop::parse("Weapons:    Cost  Damage  Armor\nDagger  8  four  0\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 12));
+        let error = Shop::parse("Dagger  8  4  0\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+    }
+
+    #[test]
+    fn test_frontier() {
+        let shop = Shop::default();
+        let boss = Combatant::parse("Hit Points: 103\nDamage: 9\nArmor: 2\n").unwrap();
+        let results = fight_results(&shop, &puzzle_slots(), PLAYER_HIT_POINTS, &boss).unwrap();
+        let frontier = pareto_frontier(&results);
+
+        // Cheapest first, each buying a bigger margin than the last.
+        assert!(frontier
+            .windows(2)
+            .all(|pair| pair[0].0.cost() < pair[1].0.cost()
+                && pair[0].1.margin() < pair[1].1.margin()));
+        // Nothing off the frontier beats anything on it.
+        for (loadout, outcome) in &results {
+            assert!(frontier
+                .iter()
+                .any(|(best, best_outcome)| best.cost() <= loadout.cost()
+                    && best_outcome.margin() >= outcome.margin()));
+        }
+        assert_eq!(frontier[0].0.cost(), 8);
+        assert_eq!(cheapest_win(&results), Some(121));
+    }
+
+    #[test]
+    fn test_slots() {
+        // With two weapons allowed and no rings, two cheap weapons beat one expensive one.
+        let shop = Shop::default();
+        let boss = Combatant {
+            hit_points: 20,
+            damage: 5,
+            ..Default::default()
+        };
+        let slots = [Slot::new("Weapons", 1, 2)];
+        let results = fight_results(&shop, &slots, 10, &boss).unwrap();
+        assert_eq!(results.len(), 5 + 10);
+        let (loadout, _) = pareto_frontier(&results)
+            .into_iter()
+            .find(|(_, outcome)| outcome.player_wins())
+            .unwrap();
+        assert_eq!(loadout.to_string(), "Dagger, Warhammer");
+    }
+
+    #[test]
+    fn test_every_loadout() {
+        let shop = Shop::default();
+        let bosses = [
+            "Hit Points: 103\nDamage: 9\nArmor: 2\n",
+            "Hit Points: 100\nDamage: 8\nArmor: 2\n",
+            "Hit Points: 104\nDamage: 8\nArmor: 1\n",
+            "Hit Points: 1\nDamage: 100\nArmor: 100\n",
+        ];
+        for boss in bosses {
+            let boss = Combatant::parse(boss).unwrap();
+            let results = fight_results(&shop, &puzzle_slots(), PLAYER_HIT_POINTS, &boss).unwrap();
+            for (loadout, outcome) in results {
+                let player = loadout.stats(PLAYER_HIT_POINTS);
+                assert_eq!(
+                    outcome.player_wins(),
+                    simulate(&player, &boss),
+                    "{} against {:?}",
+                    loadout,
+                    boss
+                );
+                let log = battle_log(&player, &boss);
+                let (attacks, last_line) = (log.lines().count() - 1, log.lines().last().unwrap());
+                if outcome.player_wins() {
+                    assert_eq!(attacks as u32, 2 * outcome.player_rounds - 1);
+                    assert_eq!(last_line, "The player wins.");
+                } else {
+                    assert_eq!(attacks as u32, 2 * outcome.boss_rounds);
+                    assert_eq!(last_line, "The boss wins.");
+                }
+            }
+        }
+    }
+
+    quickcheck! {
+        fn prop_closed_form_matches_simulation(player: (u8, u8, u8), boss: (u8, u8, u8)) -> bool {
+            // Both fighters start the fight alive.
+            let stats = |(hit_points, damage, armor): (u8, u8, u8)| Combatant {
+                hit_points: u32::from(hit_points) + 1,
+                damage: u32::from(damage),
+                armor: u32::from(armor),
+                mana: 0,
+            };
+            let (player, boss) = (stats(player), stats(boss));
+            fight(&player, &boss).player_wins() == simulate(&player, &boss)
+        }
+    }
+}
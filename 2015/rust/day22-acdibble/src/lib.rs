@@ -0,0 +1,509 @@
+use advent_support::{dijkstra, Problem, Side, Visited};
+use anyhow::{anyhow, Result};
+use aoc2015_day22::{Engine, Outcome, Turn};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+impl Spell {
+    fn named(name: &str) -> Option<Self> {
+        SPELLBOOK.iter().copied().find(|spell| spell.name() == name)
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Spell::MagicMissile => "Magic Missile",
+            Spell::Drain => "Drain",
+            Spell::Shield => "Shield",
+            Spell::Poison => "Poison",
+            Spell::Recharge => "Recharge",
+        }
+    }
+}
+
+const SPELLBOOK: [Spell; 5] = [
+    Spell::MagicMissile,
+    Spell::Drain,
+    Spell::Shield,
+    Spell::Poison,
+    Spell::Recharge,
+];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Person {
+    hp: u32,
+    damage: u32,
+    armor: u32,
+    mana: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Effect(Spell, u32);
+
+impl Effect {
+    fn new(spell: Spell) -> Option<Self> {
+        match spell {
+            Spell::Shield | Spell::Poison => Some(Self(spell, 6)),
+            Spell::Recharge => Some(Self(spell, 5)),
+            _ => None,
+        }
+    }
+
+    fn run(&mut self, player: &mut Person, boss: &mut Person) {
+        self.1 -= 1;
+        match self.0 {
+            Spell::Shield => {
+                if self.1 == 0 {
+                    player.armor -= 7;
+                }
+            }
+            Spell::Poison => boss.hp = boss.hp.saturating_sub(3),
+            Spell::Recharge => player.mana += 101,
+            _ => unreachable!(),
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.1 != 0
+    }
+}
+
+impl Person {
+    fn attack(&self, other: &mut Self) {
+        let damage = match self.damage.saturating_sub(other.armor) {
+            0 => 1,
+            n => n,
+        };
+        other.hp = other.hp.saturating_sub(damage)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.hp > 0
+    }
+
+    fn cast(&mut self, spell: Spell, boss: &mut Person) -> Result<(Option<Effect>, u32), ()> {
+        let cost = match spell {
+            Spell::MagicMissile => 53,
+            Spell::Drain => 73,
+            Spell::Shield => 113,
+            Spell::Poison => 173,
+            Spell::Recharge => 229,
+        };
+
+        if self.mana < cost {
+            return Err(());
+        }
+
+        match spell {
+            Spell::MagicMissile => boss.hp = boss.hp.saturating_sub(4),
+            Spell::Drain => {
+                self.hp += 2;
+                boss.hp = boss.hp.saturating_sub(2);
+            }
+            Spell::Shield => self.armor += 7,
+            Spell::Poison | Spell::Recharge => {}
+        }
+
+        self.mana -= cost;
+        Ok((Effect::new(spell), cost))
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Battle {
+    player: Person,
+    boss: Person,
+    effects: Vec<Effect>,
+}
+
+impl Battle {
+    fn new(boss: Person) -> Self {
+        Self {
+            player: Person {
+                hp: 50,
+                armor: 0,
+                damage: 0,
+                mana: 500,
+            },
+            boss,
+            effects: Vec::new(),
+        }
+    }
+}
+
+struct Rounds {
+    hard_mode: bool,
+}
+
+impl Rounds {
+    /// The player's turn: effects, then casting `spell`. Returns the mana spent, or why the
+    /// turn went nowhere: the spell could not be cast or the player died. Either way `battle`
+    /// is left as the turn ended.
+    fn player_turn(&self, battle: &mut Battle, spell: Spell) -> Result<u32, Outcome> {
+        let Battle {
+            player,
+            boss,
+            effects,
+        } = battle;
+
+        if self.hard_mode {
+            player.hp -= 1;
+
+            if !player.is_alive() {
+                return Err(Outcome::Lose);
+            }
+        }
+
+        for effect in effects.iter_mut() {
+            effect.run(player, boss);
+        }
+
+        if !boss.is_alive() {
+            return Ok(0);
+        }
+
+        effects.retain(|e| e.is_active());
+
+        if effects.iter().any(|eff| eff.0 == spell) {
+            return Err(Outcome::Invalid);
+        }
+
+        let (effect, cost) = player.cast(spell, boss).map_err(|()| Outcome::Invalid)?;
+        effects.extend(effect);
+        Ok(cost)
+    }
+
+    /// The boss's turn: effects, then the boss attacks if it is still alive.
+    fn boss_turn(&self, battle: &mut Battle) -> Result<(), Outcome> {
+        let Battle {
+            player,
+            boss,
+            effects,
+        } = battle;
+
+        for effect in effects.iter_mut() {
+            effect.run(player, boss);
+        }
+
+        if boss.is_alive() {
+            effects.retain(|e| e.is_active());
+
+            boss.attack(player);
+
+            if !player.is_alive() {
+                return Err(Outcome::Lose);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Play one round casting `spell`, returning the battle after it and the mana spent, or
+    /// why the round went nowhere.
+    fn play(&self, battle: &Battle, spell: Spell) -> Result<(Battle, u32), Outcome> {
+        let mut battle = battle.clone();
+        let mana_expended = self.player_turn(&mut battle, spell)?;
+        if battle.boss.is_alive() {
+            self.boss_turn(&mut battle)?;
+        }
+        Ok((battle, mana_expended))
+    }
+}
+
+impl Problem for Rounds {
+    type State = Battle;
+    type Cost = u32;
+
+    fn successors(&self, battle: &Battle) -> Vec<(Battle, u32)> {
+        SPELLBOOK
+            .iter()
+            .filter_map(|spell| self.play(battle, *spell).ok())
+            .collect()
+    }
+
+    fn is_goal(&self, battle: &Battle) -> bool {
+        !battle.boss.is_alive()
+    }
+}
+
+fn do_battle(boss: Person, hard_mode: bool) -> u32 {
+    dijkstra(&Rounds { hard_mode }, Battle::new(boss), Visited::default())
+        .map_or(u32::MAX, |found| found.cost)
+}
+
+pub fn part_one(boss: Person) -> u32 {
+    do_battle(boss, false)
+}
+
+pub fn part_two(boss: Person) -> u32 {
+    do_battle(boss, true)
+}
+
+pub fn parse_boss(input: &str) -> Person {
+    let mut lines_it = input.lines();
+    let line = lines_it.next().unwrap();
+    let mut it = line.split_ascii_whitespace();
+    it.next();
+    it.next();
+    let hp = it.next().unwrap().parse().unwrap();
+
+    let line = lines_it.next().unwrap();
+    let mut it = line.split_ascii_whitespace();
+    it.next();
+    let damage = it.next().unwrap().parse().unwrap();
+
+    Person {
+        hp,
+        damage,
+        armor: 0,
+        mana: 0,
+    }
+}
+
+/// This implementation behind the interface shared with `aoc2015-day22`.
+pub struct Simulator;
+
+impl Engine for Simulator {
+    fn play(
+        &self,
+        boss_hp: u32,
+        boss_damage: u32,
+        hard_mode: bool,
+        spells: &[&str],
+    ) -> Result<Vec<Turn>> {
+        let rounds = Rounds { hard_mode };
+        let mut battle = Battle::new(Person {
+            hp: boss_hp,
+            damage: boss_damage,
+            armor: 0,
+            mana: 0,
+        });
+        let mut mana_spent = 0;
+        let turn = |side, outcome, battle: &Battle, mana_spent| {
+            let mut effects: Vec<(String, u32)> = battle
+                .effects
+                .iter()
+                .filter(|effect| effect.is_active())
+                .map(|Effect(spell, turns)| (spell.name().to_string(), *turns))
+                .collect();
+            effects.sort();
+            Turn {
+                side,
+                outcome,
+                player_hp: battle.player.hp,
+                player_armor: battle.player.armor,
+                player_mana: battle.player.mana,
+                boss_hp: battle.boss.hp,
+                mana_spent,
+                effects,
+            }
+        };
+
+        let mut played = Vec::new();
+        for name in spells {
+            let spell = Spell::named(name).ok_or_else(|| anyhow!("unknown spell {:?}", name))?;
+            let outcome = match rounds.player_turn(&mut battle, spell) {
+                Ok(cost) => {
+                    mana_spent += cost;
+                    if battle.boss.is_alive() {
+                        Outcome::Continue
+                    } else {
+                        Outcome::Win
+                    }
+                }
+                Err(outcome) => outcome,
+            };
+            played.push(turn(Side::Player, outcome, &battle, mana_spent));
+            if outcome != Outcome::Continue {
+                break;
+            }
+
+            let outcome = match rounds.boss_turn(&mut battle) {
+                Ok(()) if !battle.boss.is_alive() => Outcome::Win,
+                Ok(()) => Outcome::Continue,
+                Err(outcome) => outcome,
+            };
+            played.push(turn(Side::Boss, outcome, &battle, mana_spent));
+            if outcome != Outcome::Continue {
+                break;
+            }
+        }
+        Ok(played)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2015_day22::{cheapest_spells, Day22, Rules};
+    use quickcheck::{quickcheck, Arbitrary, Gen, TestResult};
+
+    use super::*;
+
+    const NAMES: [&str; 5] = ["Magic Missile", "Drain", "Shield", "Poison", "Recharge"];
+
+    #[derive(Clone, Debug)]
+    struct Fight {
+        boss_hp: u32,
+        boss_damage: u32,
+        hard_mode: bool,
+        spells: Vec<&'static str>,
+    }
+
+    impl Arbitrary for Fight {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let below = |g: &mut Gen, limit: u32| u32::arbitrary(g) % limit;
+            let length = usize::arbitrary(g) % 16;
+            Fight {
+                boss_hp: 1 + below(g, 80),
+                boss_damage: 1 + below(g, 15),
+                hard_mode: bool::arbitrary(g),
+                spells: (0..length).map(|_| *g.choose(&NAMES).unwrap()).collect(),
+            }
+        }
+
+        /// Drop spells from the end, to find the shortest sequence that still disagrees.
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let fight = self.clone();
+            Box::new((0..self.spells.len()).rev().map(move |length| Fight {
+                spells: fight.spells[..length].to_vec(),
+                ..fight.clone()
+            }))
+        }
+    }
+
+    /// The first turn where the two engines disagree, if any.
+    fn first_divergence(fight: &Fight) -> Option<String> {
+        let play = |engine: &dyn Engine| {
+            engine
+                .play(
+                    fight.boss_hp,
+                    fight.boss_damage,
+                    fight.hard_mode,
+                    &fight.spells,
+                )
+                .unwrap()
+        };
+        let (day22, acdibble) = (play(&Day22), play(&Simulator));
+        (0..day22.len().max(acdibble.len())).find_map(|index| {
+            let (ours, theirs) = (day22.get(index), acdibble.get(index));
+            (ours != theirs).then(|| {
+                let side = if index % 2 == 0 { "player" } else { "boss" };
+                format!(
+                    "round {}, {} turn: day22 has {:?}, acdibble has {:?}",
+                    index / 2 + 1,
+                    side,
+                    ours,
+                    theirs
+                )
+            })
+        })
+    }
+
+    #[test]
+    fn test_cheapest_spells_agree() {
+        for hard_mode in [false, true] {
+            let (mana_spent, spells) =
+                cheapest_spells(&Rules::default(), "Hit Points: 55\nDamage: 8\n", hard_mode)
+                    .unwrap();
+            let fight = Fight {
+                boss_hp: 55,
+                boss_damage: 8,
+                hard_mode,
+                spells: spells
+                    .iter()
+                    .map(|name| *NAMES.iter().find(|known| *known == name).unwrap())
+                    .collect(),
+            };
+            assert_eq!(first_divergence(&fight), None);
+
+            let last = Simulator
+                .play(55, 8, hard_mode, &fight.spells)
+                .unwrap()
+                .pop()
+                .unwrap();
+            assert_eq!((last.outcome, last.mana_spent), (Outcome::Win, mana_spent));
+            assert_eq!(
+                mana_spent,
+                do_battle(parse_boss("Hit Points: 55\nDamage: 8\n"), hard_mode)
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_spell() {
+        for engine in [&Day22 as &dyn Engine, &Simulator] {
+            assert!(engine.play(55, 8, false, &["Drain", "Fireball"]).is_err());
+            assert_eq!(engine.play(55, 8, false, &["Drain"]).unwrap().len(), 2);
+        }
+    }
+
+    /// A boss to find the cheapest win against.
+    #[derive(Clone, Debug)]
+    struct Boss {
+        hp: u32,
+        damage: u32,
+        hard_mode: bool,
+    }
+
+    impl Arbitrary for Boss {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let below = |g: &mut Gen, limit: u32| u32::arbitrary(g) % limit;
+            Boss {
+                hp: 1 + below(g, 80),
+                damage: 1 + below(g, 15),
+                hard_mode: bool::arbitrary(g),
+            }
+        }
+    }
+
+    /// The least mana each solver spends to win, `None` when it cannot.
+    fn cheapest(boss: &Boss) -> (Option<u32>, Option<u32>) {
+        let input = format!("Hit Points: {}\nDamage: {}\n", boss.hp, boss.damage);
+        let ours = cheapest_spells(&Rules::default(), &input, boss.hard_mode)
+            .ok()
+            .map(|(mana_spent, _)| mana_spent);
+        let theirs = Some(do_battle(parse_boss(&input), boss.hard_mode))
+            .filter(|mana_spent| *mana_spent != u32::MAX);
+        (ours, theirs)
+    }
+
+    #[test]
+    fn test_cheapest_win_agrees() {
+        let boss = Boss {
+            hp: 31,
+            damage: 15,
+            hard_mode: false,
+        };
+        assert_eq!(cheapest(&boss), (Some(741), Some(741)));
+        let boss = Boss {
+            hard_mode: true,
+            ..boss
+        };
+        assert_eq!(cheapest(&boss), (None, None));
+    }
+
+    quickcheck! {
+        fn prop_cheapest_win_agrees(boss: Boss) -> TestResult {
+            match cheapest(&boss) {
+                (ours, theirs) if ours == theirs => TestResult::passed(),
+                (ours, theirs) => TestResult::error(format!(
+                    "{:?}: day22 spends {:?}, acdibble {:?}",
+                    boss, ours, theirs
+                )),
+            }
+        }
+
+        fn prop_engines_agree(fight: Fight) -> TestResult {
+            match first_divergence(&fight) {
+                Some(divergence) => TestResult::error(format!("{:?}\n{}", fight, divergence)),
+                None => TestResult::passed(),
+            }
+        }
+    }
+}
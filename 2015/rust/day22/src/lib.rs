@@ -0,0 +1,735 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use advent_support::{dijkstra, Battle, Combat, Combatant, Problem, Side, Solution, Step, Verdict};
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+/// The rules as the puzzle gives them.
+const PUZZLE_RULES: &str = include_str!("../rules.toml");
+
+/// What a spell does to the player and the opponent at one point in its life.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Effect {
+    pub damage: u32,
+    pub heal: u32,
+    pub mana: u32,
+    /// Added to the player's armor, negative to take it away again.
+    pub armor: i32,
+}
+
+impl Effect {
+    fn apply(&self, player: &mut Combatant, opponent: &mut Combatant) {
+        opponent.hit_points = opponent.hit_points.saturating_sub(self.damage);
+        player.hit_points = player.hit_points.saturating_add(self.heal);
+        player.mana = player.mana.saturating_add(self.mana);
+        player.armor = player.armor.saturating_add_signed(self.armor);
+    }
+
+    /// What the effect does, like "deals 2 damage and heals 2 hit points".
+    fn describe(&self) -> Option<String> {
+        let mut phrases = Vec::new();
+        if self.damage > 0 {
+            phrases.push(format!("deals {} damage", self.damage));
+        }
+        if self.heal > 0 {
+            phrases.push(format!("heals {} hit points", self.heal));
+        }
+        if self.mana > 0 {
+            phrases.push(format!("provides {} mana", self.mana));
+        }
+        match self.armor.cmp(&0) {
+            Ordering::Greater => phrases.push(format!("increases armor by {}", self.armor)),
+            Ordering::Less => phrases.push(format!("decreases armor by {}", -self.armor)),
+            Ordering::Equal => {}
+        }
+        (!phrases.is_empty()).then(|| phrases.join(" and "))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Spell {
+    pub name: String,
+    pub cost: u32,
+    /// Turns the spell stays active for, zero for one that only acts as it is cast.
+    #[serde(default)]
+    pub duration: u32,
+    /// Happens as the spell is cast.
+    #[serde(default)]
+    pub on_apply: Effect,
+    /// Happens at the start of every turn while the spell is active.
+    #[serde(default)]
+    pub each_turn: Effect,
+    /// Happens as the spell wears off.
+    #[serde(default)]
+    pub on_expire: Effect,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Player {
+    pub hp: u32,
+    pub damage: u32,
+    pub armor: u32,
+    pub mana: u32,
+}
+
+impl Player {
+    fn combatant(&self) -> Combatant {
+        Combatant {
+            hit_points: self.hp,
+            damage: self.damage,
+            armor: self.armor,
+            mana: self.mana,
+        }
+    }
+}
+
+/// Everything about a fight that can change from one rule set to the next.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Rules {
+    pub player: Player,
+    /// Fight this boss rather than the one described by the puzzle input.
+    pub boss: Option<Player>,
+    #[serde(rename = "spell")]
+    pub spells: Vec<Spell>,
+}
+
+impl Rules {
+    /// Read rules in the format of `rules.toml`.
+    pub fn parse(text: &str) -> Result<Self> {
+        let rules: Rules = toml::from_str(text)?;
+        if rules.spells.is_empty() {
+            bail!("there are no spells to cast");
+        }
+        // Free spells would let the search go round in circles without spending anything.
+        if let Some(spell) = rules.spells.iter().find(|spell| spell.cost == 0) {
+            bail!("{} costs no mana", spell.name);
+        }
+        Ok(rules)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::parse(PUZZLE_RULES).expect("the puzzle rules parse")
+    }
+}
+
+/// The puzzle's rules, parsed the first time they are needed.
+fn puzzle_rules() -> &'static Rules {
+    static RULES: OnceLock<Rules> = OnceLock::new();
+    RULES.get_or_init(Rules::default)
+}
+
+/// A fight where active spells are known by their index.
+type Duel = Battle<usize>;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct State {
+    battle: Duel,
+    /// Index of the spell cast in the round that led here.
+    spell_cast: usize,
+}
+
+/// The fight, where the player casts a spell each turn. It is also a search over rounds, where
+/// each round costs the mana of the spell cast in it.
+struct Fight<'a> {
+    spells: &'a [Spell],
+    hard_mode: bool,
+}
+
+impl<'a> Combat for Fight<'a> {
+    type Status = usize;
+    /// The index of the spell to cast. With none to cast the player cannot move.
+    type Move = Option<usize>;
+
+    fn upkeep(&self, side: Side, battle: &mut Duel) {
+        if self.hard_mode && side == Side::Player {
+            battle.player.hit_points = battle.player.hit_points.saturating_sub(1);
+        }
+    }
+
+    fn each_turn(&self, status: &usize, player: &mut Combatant, boss: &mut Combatant) {
+        self.spells[*status].each_turn.apply(player, boss);
+    }
+
+    fn on_expire(&self, status: &usize, player: &mut Combatant, boss: &mut Combatant) {
+        self.spells[*status].on_expire.apply(player, boss);
+    }
+
+    fn player_move(&self, choice: &Option<usize>, battle: &mut Duel) -> Verdict {
+        let Some(index) = *choice else {
+            return Verdict::Invalid;
+        };
+        let spell = &self.spells[index];
+
+        if spell.cost > battle.player.mana {
+            return Verdict::Invalid;
+        }
+        if spell.duration > 0 && !battle.start_effect(index, spell.duration) {
+            return Verdict::Invalid;
+        }
+        spell.on_apply.apply(&mut battle.player, &mut battle.boss);
+
+        battle.player.mana -= spell.cost;
+        battle.mana_spent = battle.mana_spent.saturating_add(spell.cost);
+
+        Verdict::Continue
+    }
+}
+
+impl<'a> Problem for Fight<'a> {
+    type State = State;
+    type Cost = u32;
+
+    fn successors(&self, state: &State) -> Vec<(State, u32)> {
+        (0..self.spells.len())
+            .filter_map(|spell_cast| {
+                let mut battle = state.battle.clone();
+                match battle.round(self, &Some(spell_cast)) {
+                    Verdict::Continue | Verdict::Win => {
+                        let cost = battle.mana_spent - state.battle.mana_spent;
+                        Some((State { battle, spell_cast }, cost))
+                    }
+                    Verdict::Invalid | Verdict::Lose => None,
+                }
+            })
+            .collect()
+    }
+
+    fn is_goal(&self, state: &State) -> bool {
+        !state.battle.boss.is_alive()
+    }
+}
+
+/// The least mana that wins, with the spells cast to do it.
+fn run(spells: &[Spell], start: &Duel, hard_mode: bool) -> Option<(u32, Vec<usize>)> {
+    // States come off the queue cheapest first, so a fight that stands exactly as one already
+    // expanded, effects and all, cannot lead anywhere cheaper.
+    let mut seen = HashSet::new();
+    let found = dijkstra(
+        &Fight { spells, hard_mode },
+        State {
+            battle: start.clone(),
+            spell_cast: 0,
+        },
+        |state: &State, _| {
+            let battle = &state.battle;
+            seen.insert((battle.player, battle.boss, battle.effects.clone()))
+        },
+    )?;
+    // A round where the effects finish the boss off before the player's turn casts nothing.
+    let cast = found
+        .path
+        .windows(2)
+        .filter(|pair| pair[1].battle.mana_spent > pair[0].battle.mana_spent)
+        .map(|pair| pair[1].spell_cast)
+        .collect();
+    Some((found.cost, cast))
+}
+
+fn initial_battle(rules: &Rules, input: &str) -> Result<Duel> {
+    let opponent = match rules.boss {
+        Some(boss) => boss.combatant(),
+        None => Combatant::parse(input).context("reading the boss")?,
+    };
+    Ok(Battle::new(rules.player.combatant(), opponent))
+}
+
+/// The least mana that wins the fight and the names of the spells to cast, in order.
+pub fn cheapest_spells(rules: &Rules, input: &str, hard_mode: bool) -> Result<(u32, Vec<String>)> {
+    let (mana_spent, cast) = run(&rules.spells, &initial_battle(rules, input)?, hard_mode)
+        .ok_or_else(|| anyhow!("No solution found"))?;
+    let names = cast
+        .into_iter()
+        .map(|index| rules.spells[index].name.clone())
+        .collect();
+    Ok((mana_spent, names))
+}
+
+fn solve(rules: &Rules, input: &str, hard_mode: bool) -> Result<String> {
+    cheapest_spells(rules, input, hard_mode).map(|(mana_spent, _)| mana_spent.to_string())
+}
+
+/// Add what `step` of `side`'s turn did to `log`, given the battle before and after it.
+fn describe_step(
+    log: &mut String,
+    (side, step): (Side, Step),
+    spells: &[Spell],
+    spell_cast: usize,
+    before: &Duel,
+    after: &Duel,
+) -> std::fmt::Result {
+    match (step, side) {
+        (Step::Upkeep, _) => {
+            if !log.is_empty() {
+                log.push('\n');
+            }
+            let turn = if side == Side::Player {
+                "Player"
+            } else {
+                "Boss"
+            };
+            writeln!(log, "-- {} turn --", turn)?;
+            writeln!(
+                log,
+                "- Player has {} hit points, {} armor, {} mana",
+                before.player.hit_points, before.player.armor, before.player.mana
+            )?;
+            writeln!(log, "- Boss has {} hit points", before.boss.hit_points)?;
+            if after.player.hit_points < before.player.hit_points {
+                writeln!(log, "Player loses 1 hit point.")?;
+            }
+        }
+        (Step::Effects, _) => {
+            for spell_index in before.effects.keys() {
+                let spell = &spells[*spell_index];
+                let timer = after.effects.get(spell_index).unwrap_or(&0);
+                match spell.each_turn.describe() {
+                    Some(effect) => writeln!(
+                        log,
+                        "{} {}; its timer is now {}.",
+                        spell.name, effect, timer
+                    )?,
+                    None => writeln!(log, "{}'s timer is now {}.", spell.name, timer)?,
+                }
+                if *timer == 0 {
+                    match spell.on_expire.describe() {
+                        Some(effect) => writeln!(log, "{} wears off and {}.", spell.name, effect)?,
+                        None => writeln!(log, "{} wears off.", spell.name)?,
+                    }
+                }
+            }
+        }
+        (Step::Move, Side::Player) => {
+            // An invalid cast leaves the battle as it was, and is reported by the caller.
+            if after.mana_spent > before.mana_spent {
+                let spell = &spells[spell_cast];
+                match spell.on_apply.describe() {
+                    Some(effect) => {
+                        writeln!(log, "Player casts {}, which {}.", spell.name, effect)?
+                    }
+                    None => writeln!(log, "Player casts {}.", spell.name)?,
+                }
+            }
+        }
+        (Step::Move, Side::Boss) => {
+            // Not the hit points lost, which a killing blow can leave short.
+            let damage = before.boss.hit(&before.player);
+            if before.player.armor > 0 {
+                writeln!(
+                    log,
+                    "Boss attacks for {} - {} = {} damage.",
+                    before.boss.damage, before.player.armor, damage
+                )?;
+            } else {
+                writeln!(log, "Boss attacks for {} damage.", damage)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Play the fight casting the named spells in order, logging every turn like the puzzle does.
+/// Fails if a spell cannot be cast when its turn comes.
+pub fn replay(rules: &Rules, input: &str, hard_mode: bool, names: &[&str]) -> Result<String> {
+    let sequence = names
+        .iter()
+        .map(|name| {
+            rules
+                .spells
+                .iter()
+                .position(|spell| spell.name == *name)
+                .ok_or_else(|| anyhow!("there is no spell called {:?}", name))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let spells = &rules.spells;
+    let fight = Fight { spells, hard_mode };
+    let mut battle = initial_battle(rules, input)?;
+    let mut log = String::new();
+
+    // One round past the last spell, in case effects finish the boss before the next cast.
+    for round in 0..=sequence.len() {
+        let choice = sequence.get(round).copied();
+        let mut written = Ok(());
+        let verdict = battle.round_watched(&fight, &choice, &mut |side, step, before, after| {
+            if written.is_ok() {
+                written = describe_step(
+                    &mut log,
+                    (side, step),
+                    spells,
+                    choice.unwrap_or_default(),
+                    before,
+                    after,
+                );
+            }
+        });
+        written?;
+        match verdict {
+            Verdict::Continue => {}
+            Verdict::Win => {
+                writeln!(log, "This kills the boss, and the player wins.")?;
+                writeln!(log, "Mana spent: {}", battle.mana_spent)?;
+                return Ok(log);
+            }
+            Verdict::Lose => {
+                writeln!(log, "This kills the player, and the boss wins.")?;
+                return Ok(log);
+            }
+            Verdict::Invalid => {
+                let Some(spell_cast) = choice else {
+                    writeln!(log, "The spells run out with the boss still alive.")?;
+                    return Ok(log);
+                };
+                let spell = &spells[spell_cast];
+                if spell.cost > battle.player.mana {
+                    bail!(
+                        "round {}: {} costs {} mana, the player has {}",
+                        round + 1,
+                        spell.name,
+                        spell.cost,
+                        battle.player.mana
+                    );
+                }
+                bail!("round {}: {} is still active", round + 1, spell.name);
+            }
+        }
+    }
+
+    unreachable!("the spells run out in the last round")
+}
+
+/// How a turn of the fight ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Continue,
+    /// The spell could not be cast, for want of mana or because it is still active.
+    Invalid,
+    Lose,
+    Win,
+}
+
+/// Where the fight stands after a turn.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Turn {
+    pub side: Side,
+    pub outcome: Outcome,
+    pub player_hp: u32,
+    pub player_armor: u32,
+    pub player_mana: u32,
+    pub boss_hp: u32,
+    pub mana_spent: u32,
+    /// The active spells and the turns each has left, by name.
+    pub effects: Vec<(String, u32)>,
+}
+
+/// A simulator of the wizard fight, with the puzzle's spells and a wizard with 50 hit points and
+/// 500 mana, so that different implementations can be checked against each other.
+pub trait Engine {
+    /// Cast the named spells, one a round, against the boss, reporting every turn. Stops after
+    /// the last spell or the first turn that does not end in [`Outcome::Continue`]. A spell that
+    /// cannot be cast ends the player's turn after its effects, with nothing cast. A name that is
+    /// not one of the puzzle's spells is an error.
+    fn play(
+        &self,
+        boss_hp: u32,
+        boss_damage: u32,
+        hard_mode: bool,
+        spells: &[&str],
+    ) -> Result<Vec<Turn>>;
+}
+
+impl Engine for Day22 {
+    fn play(
+        &self,
+        boss_hp: u32,
+        boss_damage: u32,
+        hard_mode: bool,
+        spells: &[&str],
+    ) -> Result<Vec<Turn>> {
+        let rules = puzzle_rules();
+        let fight = Fight {
+            spells: &rules.spells,
+            hard_mode,
+        };
+        let boss = Combatant {
+            hit_points: boss_hp,
+            damage: boss_damage,
+            ..Default::default()
+        };
+        let mut battle = Battle::new(rules.player.combatant(), boss);
+        let turn = |side, outcome, battle: &Duel| {
+            let mut effects: Vec<(String, u32)> = battle
+                .effects
+                .iter()
+                .map(|(index, turns)| (rules.spells[*index].name.clone(), *turns))
+                .collect();
+            effects.sort();
+            Turn {
+                side,
+                outcome,
+                player_hp: battle.player.hit_points,
+                player_armor: battle.player.armor,
+                player_mana: battle.player.mana,
+                boss_hp: battle.boss.hit_points,
+                mana_spent: battle.mana_spent,
+                effects,
+            }
+        };
+
+        let mut turns = Vec::new();
+        for name in spells {
+            let spell_cast = rules
+                .spells
+                .iter()
+                .position(|spell| spell.name == *name)
+                .ok_or_else(|| anyhow!("unknown spell {:?}", name))?;
+            // Where each turn of the round stands after its last step.
+            let mut ended: Vec<(Side, Duel)> = Vec::new();
+            let verdict = battle.round_watched(
+                &fight,
+                &Some(spell_cast),
+                &mut |side, _, _, after| match ended.last_mut() {
+                    Some((last, battle)) if *last == side => *battle = after.clone(),
+                    _ => ended.push((side, after.clone())),
+                },
+            );
+            let outcome = match verdict {
+                Verdict::Continue => Outcome::Continue,
+                Verdict::Invalid => Outcome::Invalid,
+                Verdict::Lose => Outcome::Lose,
+                Verdict::Win => Outcome::Win,
+            };
+            let last = ended.len() - 1;
+            for (index, (side, battle)) in ended.iter().enumerate() {
+                let outcome = if index == last {
+                    outcome
+                } else {
+                    Outcome::Continue
+                };
+                turns.push(turn(*side, outcome, battle));
+            }
+            if outcome != Outcome::Continue {
+                break;
+            }
+        }
+        Ok(turns)
+    }
+}
+
+/// The puzzle, played by the rules in `rules.toml`.
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part1(&self, input: &str) -> Result<String> {
+        solve(&Rules::default(), input, false)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        solve(&Rules::default(), input, true)
+    }
+}
+
+/// The puzzle played by other rules.
+pub struct WithRules(pub Rules);
+
+impl Solution for WithRules {
+    fn part1(&self, input: &str) -> Result<String> {
+        solve(&self.0, input, false)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        solve(&self.0, input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The puzzle's examples, where the player starts with 10 hit points and 250 mana.
+    fn example_rules() -> Rules {
+        Rules {
+            player: Player {
+                hp: 10,
+                mana: 250,
+                ..Default::default()
+            },
+            ..Rules::default()
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let rules = example_rules();
+        // Poison, then Magic Missile.
+        assert_eq!(
+            solve(&rules, "Hit Points: 13\nDamage: 8\n", false).unwrap(),
+            "226"
+        );
+        // Recharge, Shield, Drain, Poison, Magic Missile.
+        assert_eq!(
+            solve(&rules, "Hit Points: 14\nDamage: 8\n", false).unwrap(),
+            "641"
+        );
+    }
+
+    #[test]
+    fn test_cheapest_spells() {
+        let rules = example_rules();
+        assert_eq!(
+            cheapest_spells(&rules, "Hit Points: 13\nDamage: 8\n", false).unwrap(),
+            (226, vec!["Poison".to_string(), "Magic Missile".to_string()])
+        );
+
+        // Pruning that ignored active effects missed this, and found 907.
+        assert_eq!(
+            cheapest_spells(&Rules::default(), "Hit Points: 31\nDamage: 15\n", false).unwrap(),
+            (
+                741,
+                ["Poison", "Recharge", "Shield", "Poison", "Magic Missile"]
+                    .iter()
+                    .map(|name| name.to_string())
+                    .collect()
+            )
+        );
+
+        let (mana_spent, spells) =
+            cheapest_spells(&Rules::default(), "Hit Points: 55\nDamage: 8\n", true).unwrap();
+        let names: Vec<&str> = spells.iter().map(String::as_str).collect();
+        let log = replay(
+            &Rules::default(),
+            "Hit Points: 55\nDamage: 8\n",
+            true,
+            &names,
+        )
+        .unwrap();
+        assert!(log.ends_with(&format!("the player wins.\nMana spent: {}\n", mana_spent)));
+    }
+
+    #[test]
+    fn test_replay() {
+        let rules = example_rules();
+        let log = replay(
+            &rules,
+            "Hit Points: 13\nDamage: 8\n",
+            false,
+            &["Poison", "Magic Missile"],
+        )
+        .unwrap();
+        assert_eq!(
+            log,
+            "-- Player turn --
+- Player has 10 hit points, 0 armor, 250 mana
+- Boss has 13 hit points
+Player casts Poison.
+
+-- Boss turn --
+- Player has 10 hit points, 0 armor, 77 mana
+- Boss has 13 hit points
+Poison deals 3 damage; its timer is now 5.
+Boss attacks for 8 damage.
+
+-- Player turn --
+- Player has 2 hit points, 0 armor, 77 mana
+- Boss has 10 hit points
+Poison deals 3 damage; its timer is now 4.
+Player casts Magic Missile, which deals 4 damage.
+
+-- Boss turn --
+- Player has 2 hit points, 0 armor, 24 mana
+- Boss has 3 hit points
+Poison deals 3 damage; its timer is now 3.
+This kills the boss, and the player wins.
+Mana spent: 226
+"
+        );
+
+        let log = replay(&rules, "Hit Points: 14\nDamage: 8\n", false, &["Shield"]).unwrap();
+        assert!(log.contains("Boss attacks for 8 - 7 = 1 damage."));
+        assert!(log.ends_with("The spells run out with the boss still alive.\n"));
+
+        let log = replay(
+            &rules,
+            "Hit Points: 30\nDamage: 8\n",
+            false,
+            &["Magic Missile", "Magic Missile"],
+        )
+        .unwrap();
+        assert!(log
+            .ends_with("Boss attacks for 8 damage.\nThis kills the player, and the boss wins.\n"));
+
+        assert!(replay(&rules, "Hit Points: 14\nDamage: 8\n", false, &["Fireball"]).is_err());
+        let error = replay(
+            &Rules::default(),
+            "Hit Points: 14\nDamage: 8\n",
+            false,
+            &["Poison", "Poison"],
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "round 2: Poison is still active");
+    }
+
+    #[test]
+    fn test_variant_rules() {
+        let rules = Rules::parse(
+            "[player]
+hp = 10
+mana = 250
+
+[boss]
+hp = 13
+damage = 8
+
+[[spell]]
+name = \"Fireball\"
+cost = 100
+on_apply = { damage = 13 }
+",
+        )
+        .unwrap();
+        // The boss in the rules wins over the input.
+        assert_eq!(WithRules(rules.clone()).part1("").unwrap(), "100");
+
+        let mut rules = rules;
+        rules.player.mana = 99;
+        assert!(WithRules(rules).part1("").is_err());
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert!(Rules::parse("[player]\nhp = 1\n").is_err());
+        assert!(Rules::parse("[player]\n[[spell]]\nname = \"Nap\"\ncost = 0\n").is_err());
+        assert!(
+            Rules::parse("[player]\n[[spell]]\nname = \"Zap\"\ncost = 1\nrange = 3\n").is_err()
+        );
+
+        let rules = Rules::default();
+        assert!(initial_battle(&rules, "Hit Points: 12\nSpeed: 3\n").is_err());
+        assert!(initial_battle(&rules, "Damage: 3\n").is_err());
+        assert_eq!(
+            initial_battle(&rules, "Damage: 3\nHit Points: 12")
+                .unwrap()
+                .boss,
+            Combatant {
+                hit_points: 12,
+                damage: 3,
+                ..Default::default()
+            }
+        );
+    }
+}
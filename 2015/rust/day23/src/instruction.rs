@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use advent_support::{Flow, Registers};
+
+#[derive(Debug, PartialEq, Eq)]
 pub enum Instruction {
     Hlf(usize),
     Tpl(usize),
@@ -7,3 +9,25 @@ pub enum Instruction {
     Jie((usize, i64)),
     Jio((usize, i64)),
 }
+
+impl advent_support::Instruction for Instruction {
+    fn execute(&self, registers: &mut Registers) -> Flow {
+        match *self {
+            Instruction::Hlf(register) => registers[register] /= 2,
+            Instruction::Tpl(register) => registers[register] *= 3,
+            Instruction::Inc(register) => registers[register] += 1,
+            Instruction::Jmp(offset) => return Flow::Jump(offset),
+            Instruction::Jie((register, offset)) => {
+                if registers[register] % 2 == 0 {
+                    return Flow::Jump(offset);
+                }
+            }
+            Instruction::Jio((register, offset)) => {
+                if registers[register] == 1 {
+                    return Flow::Jump(offset);
+                }
+            }
+        }
+        Flow::Next
+    }
+}
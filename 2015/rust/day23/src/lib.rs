@@ -0,0 +1,80 @@
+mod instruction;
+mod parse;
+
+use advent_support::{Machine, Registers, Solution};
+use anyhow::{bail, Result};
+
+use crate::instruction::Instruction;
+use crate::parse::parse;
+
+/// Real programs finish in well under a million steps, anything longer is stuck in a loop.
+const STEP_LIMIT: usize = 10_000_000;
+/// How many of the final steps to show when a program fails.
+const TRACE_LENGTH: usize = 10;
+
+fn evaluate(
+    instructions: Vec<Instruction>,
+    initial_a: i64,
+    initial_b: i64,
+    step_limit: usize,
+) -> Result<Registers> {
+    let mut registers = Registers::new(&["a", "b"]);
+    registers[0] = initial_a;
+    registers[1] = initial_b;
+
+    let mut machine = Machine::new(instructions, registers);
+    machine.set_step_limit(step_limit);
+    machine.enable_trace(TRACE_LENGTH);
+    if let Err(error) = machine.run() {
+        bail!("{}, last steps:\n{}", error, machine.dump_trace());
+    }
+    Ok(machine.registers().clone())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let registers = evaluate(parse(input)?, 0, 0, STEP_LIMIT)?;
+        Ok(registers[1].to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let registers = evaluate(parse(input)?, 1, 0, STEP_LIMIT)?;
+        Ok(registers[1].to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let program = parse("inc a\njio a, +2\ntpl a\ninc a\n").unwrap();
+        let registers = evaluate(program, 0, 0, STEP_LIMIT).unwrap();
+        assert_eq!(registers.get("a"), Some(2));
+    }
+
+    #[test]
+    fn test_jump_before_start() {
+        let error = evaluate(parse("jmp -1\n").unwrap(), 0, 0, STEP_LIMIT).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid offset for jump at index 0, offset -1, last steps:\n       0    0  Jmp(-1)              a=0 b=0 -> a=0 b=0"
+        );
+    }
+
+    #[test]
+    fn test_endless_loop() {
+        let error = evaluate(parse("inc a\njmp -1\n").unwrap(), 0, 0, 100).unwrap_err();
+        let message = error.to_string();
+        let mut lines = message.lines();
+        assert_eq!(
+            lines.next(),
+            Some("Still running after 100 steps, last steps:")
+        );
+        assert_eq!(lines.count(), TRACE_LENGTH);
+        assert!(message.ends_with("Jmp(-1)              a=50 b=0 -> a=50 b=0"));
+    }
+}
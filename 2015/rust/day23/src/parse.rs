@@ -1,12 +1,16 @@
+use advent_support::{parse_each_line, ParseError, Span};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{char, digit1, multispace1, one_of};
 use nom::combinator::{map_res, opt, recognize};
+use nom::error::context;
 use nom::sequence::{preceded, separated_pair};
 use nom::IResult;
 
 use crate::instruction::Instruction;
 
+type Result<'a, T> = IResult<Span<'a>, T, ParseError>;
+
 fn map_register(name: char) -> usize {
     match name {
         'a' => 0,
@@ -15,21 +19,31 @@ fn map_register(name: char) -> usize {
     }
 }
 
-fn offset(input: &str) -> IResult<&str, i64> {
-    map_res(recognize(preceded(opt(one_of("-+")), digit1)), |s: &str| {
-        s.parse()
-    })(input)
+fn register(input: Span) -> Result<usize> {
+    let (input, name) = context("register `a` or `b`", one_of("ab"))(input)?;
+    Ok((input, map_register(name)))
+}
+
+fn offset(input: Span) -> Result<i64> {
+    context(
+        "an offset",
+        map_res(recognize(preceded(opt(one_of("-+")), digit1)), |s: Span| {
+            s.fragment().parse()
+        }),
+    )(input)
 }
 
-fn with_register(input: &str) -> IResult<&str, Instruction> {
-    let (input, (name, register)) = separated_pair(
-        alt((tag("hlf"), tag("inc"), tag("tpl"))),
+fn with_register(input: Span) -> Result<Instruction> {
+    let (input, (name, register_index)) = separated_pair(
+        context(
+            "`hlf`, `inc` or `tpl`",
+            alt((tag("hlf"), tag("inc"), tag("tpl"))),
+        ),
         multispace1,
-        one_of("ab"),
+        register,
     )(input)?;
 
-    let register_index = map_register(register);
-    let keyword = match name {
+    let keyword = match *name.fragment() {
         "hlf" => Instruction::Hlf,
         "inc" => Instruction::Inc,
         "tpl" => Instruction::Tpl,
@@ -38,22 +52,21 @@ fn with_register(input: &str) -> IResult<&str, Instruction> {
     Ok((input, keyword(register_index)))
 }
 
-fn jump(input: &str) -> IResult<&str, Instruction> {
-    let (input, (_, value)) = separated_pair(tag("jmp"), multispace1, offset)(input)?;
+fn jump(input: Span) -> Result<Instruction> {
+    let (input, (_, value)) =
+        separated_pair(context("`jmp`", tag("jmp")), multispace1, offset)(input)?;
     Ok((input, Instruction::Jmp(value)))
 }
 
-fn jump_if(input: &str) -> IResult<&str, Instruction> {
-    let (input, name) = alt((tag("jie"), tag("jio")))(input)?;
+fn jump_if(input: Span) -> Result<Instruction> {
+    let (input, name) = context("`jie` or `jio`", alt((tag("jie"), tag("jio"))))(input)?;
     let (input, _) = multispace1(input)?;
-    let (input, register) = one_of("ab")(input)?;
+    let (input, register_index) = register(input)?;
     let (input, _) = char(',')(input)?;
     let (input, _) = multispace1(input)?;
     let (input, offset) = offset(input)?;
 
-    let register_index = map_register(register);
-
-    let keyword = match name {
+    let keyword = match *name.fragment() {
         "jie" => Instruction::Jie,
         "jio" => Instruction::Jio,
         _ => unreachable!(),
@@ -61,20 +74,47 @@ fn jump_if(input: &str) -> IResult<&str, Instruction> {
     Ok((input, keyword((register_index, offset))))
 }
 
-fn instruction(input: &str) -> IResult<&str, Instruction> {
+fn instruction(input: Span) -> Result<Instruction> {
     alt((with_register, jump, jump_if))(input)
 }
 
-pub fn parse(input: &str) -> Vec<Instruction> {
-    let (instructions, errors): (Vec<_>, Vec<_>) =
-        input.lines().map(instruction).partition(|x| x.is_ok());
-    if !errors.is_empty() {
-        panic!("Errors: {:?}", errors);
+pub fn parse(input: &str) -> std::result::Result<Vec<Instruction>, ParseError> {
+    parse_each_line(input, instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let instructions = parse("inc a\njio a, +2\ntpl a\ninc a\n").unwrap();
+        assert_eq!(
+            instructions,
+            vec![
+                Instruction::Inc(0),
+                Instruction::Jio((0, 2)),
+                Instruction::Tpl(0),
+                Instruction::Inc(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = parse("inc a\njie c, +4\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 5));
+        assert_eq!(error.expected(), "register `a` or `b`");
+
+        let error = parse("inc a\nnop\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 1));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected `hlf`, `inc` or `tpl` or `jmp` or `jie` or `jio`\nnop\n^"
+        );
+
+        let error = parse("jmp x\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 5));
+        assert_eq!(error.expected(), "an offset");
     }
-    let instructions: Vec<_> = instructions
-        .into_iter()
-        .map(|x| x.unwrap())
-        .map(|x| x.1)
-        .collect();
-    instructions
 }
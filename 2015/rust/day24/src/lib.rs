@@ -0,0 +1,42 @@
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+fn balance(package_weights: &[usize], number_of_groups: usize) -> Option<(Vec<usize>, usize)> {
+    let total: usize = package_weights.iter().sum();
+    let weight_average = total / number_of_groups;
+    let group_size = package_weights.len() / number_of_groups;
+
+    (4..=group_size)
+        .flat_map(|size| {
+            package_weights
+                .iter()
+                .copied()
+                .combinations(size)
+                .filter(|item| item.iter().sum::<usize>() == weight_average)
+                .map(|item| (item.to_vec(), item.iter().product::<usize>()))
+                .sorted_by_key(|item| item.1)
+                .next()
+        })
+        .sorted_by_key(|item| item.1)
+        .next()
+}
+
+fn quantum_entanglement(input: &str, number_of_groups: usize) -> Result<String> {
+    let packages: Vec<usize> = advent_support::parse_lines(input)?;
+    balance(&packages, number_of_groups)
+        .map(|(_, entanglement)| entanglement.to_string())
+        .ok_or_else(|| anyhow!("packages cannot be split into {} groups", number_of_groups))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn part1(&self, input: &str) -> Result<String> {
+        quantum_entanglement(input, 3)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        quantum_entanglement(input, 4)
+    }
+}
@@ -0,0 +1,46 @@
+use advent_support::Solution;
+use anyhow::{bail, Result};
+
+fn compute_code(row: u64, column: u64, initial: u64) -> u64 {
+    let multiplier = 252533;
+    let divisor = 33554393;
+
+    let value = (row + column) - 1;
+    let target = (((value * value) + value) / 2) - (row - 1);
+    (1..target).fold(initial, |previous, _| (previous * multiplier) % divisor)
+}
+
+/// The input is a sentence naming the row and then the column of the wanted code.
+fn parse_position(input: &str) -> Result<(u64, u64)> {
+    let numbers: Vec<u64> = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|piece| !piece.is_empty())
+        .map(|piece| piece.parse())
+        .collect::<Result<_, _>>()?;
+    match numbers.as_slice() {
+        [row, column] => Ok((*row, *column)),
+        _ => bail!("expected a row and a column, found {:?}", numbers),
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let initial = 20151125;
+        let (row, column) = parse_position(input)?;
+        Ok(compute_code(row, column, initial).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_grid() {
+        assert_eq!(compute_code(1, 1, 20151125), 20151125);
+        assert_eq!(compute_code(2, 1, 20151125), 31916031);
+        assert_eq!(compute_code(6, 6, 20151125), 27995004);
+    }
+}
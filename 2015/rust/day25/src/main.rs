@@ -1,14 +1,7 @@
-fn compute_code(row: u64, column: u64, initial: u64) -> u64 {
-    let multiplier = 252533;
-    let divisor = 33554393;
+use anyhow::Result;
 
-    let value = (row + column) - 1;
-    let target = (((value * value) + value) / 2) - (row - 1);
-    (1..target).fold(initial, |previous, _| (previous * multiplier) % divisor)
-}
+use aoc2015_day25::Day25;
 
-fn main() {
-    let initial = 20151125;
-    let goal = compute_code(3010, 3019, initial);
-    println!("{goal:?}");
+fn main() -> Result<()> {
+    advent_support::run(&Day25)
 }
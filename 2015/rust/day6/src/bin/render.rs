@@ -0,0 +1,20 @@
+use std::env;
+use std::path::Path;
+
+use advent_support::read_input;
+use anyhow::{bail, Result};
+
+use aoc2015_day6::render;
+use aoc2015_day6::rule::{Brightness, OnOff};
+
+/// Reads the commands on stdin and draws the lights to the .png, .ppm or .gif file named, using
+/// either the on/off rule of part one or the brightness rule of part two.
+fn main() -> Result<()> {
+    let input = read_input::<String>()?.join("\n");
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.as_slice() {
+        [rule, path] if rule == "onoff" => render(&input, &OnOff, Path::new(path)),
+        [rule, path] if rule == "brightness" => render(&input, &Brightness, Path::new(path)),
+        _ => bail!("usage: render <onoff|brightness> <output.png|.ppm|.gif> < input"),
+    }
+}
@@ -0,0 +1,152 @@
+use advent_support::ParseError;
+use anyhow::Result;
+use pest::{iterators::Pair, Parser};
+
+#[derive(Parser)]
+#[grammar = "command.pest"]
+pub struct LightCommand;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    On,
+    Off,
+    Toggle,
+}
+
+impl From<&str> for Action {
+    fn from(i: &str) -> Self {
+        match i {
+            "turn on" => Action::On,
+            "turn off" => Action::Off,
+            "toggle" => Action::Toggle,
+            _ => unimplemented!(""),
+        }
+    }
+}
+
+/// A rectangle of lights, with both corners included. Coordinates stay below `usize::MAX` so
+/// that the edge one past the rectangle can always be named, which [`parse`] makes sure of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rectangle {
+    pub left: usize,
+    pub top: usize,
+    pub right: usize,
+    pub bottom: usize,
+}
+
+impl Rectangle {
+    /// The rectangle with top left corner `from` and bottom right corner `through`, each given as
+    /// `(x, y)`.
+    pub fn new(from: (usize, usize), through: (usize, usize)) -> Self {
+        Self {
+            left: from.0,
+            top: from.1,
+            right: through.0,
+            bottom: through.1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Command {
+    pub action: Action,
+    pub rectangle: Rectangle,
+}
+
+fn parse_action(parser: Pair<Rule>) -> Action {
+    parser.as_str().into()
+}
+
+/// The grammar only lets digits through, so the one way this fails is a number of `usize::MAX`
+/// or more. `parser` is on the `number`-th line of `input`.
+fn parse_coordinate(parser: Pair<Rule>, input: &str, number: u32) -> Result<usize, ParseError> {
+    parser
+        .as_str()
+        .parse()
+        .ok()
+        .filter(|&coordinate| coordinate < usize::MAX)
+        .ok_or_else(|| {
+            let column = parser.as_span().start() + 1;
+            ParseError::in_input(input, number, column, "a coordinate below usize::MAX")
+        })
+}
+
+fn parse_corner(
+    parser: Pair<Rule>,
+    input: &str,
+    number: u32,
+) -> Result<(usize, usize), ParseError> {
+    let mut inner = parser.into_inner();
+
+    let x = parse_coordinate(inner.next().expect("number exists"), input, number)?;
+    let y = parse_coordinate(inner.next().expect("number exists"), input, number)?;
+
+    Ok((x, y))
+}
+
+fn parse_command(input: &str, number: u32, line: &str) -> Result<Command> {
+    let result = LightCommand::parse(Rule::light_command, line)?
+        .next()
+        .unwrap();
+    let mut parts = result.into_inner();
+
+    let action: Action = parse_action(parts.next().unwrap());
+    let from = parse_corner(parts.next().unwrap(), input, number)?;
+    let through = parse_corner(parts.next().unwrap(), input, number)?;
+
+    Ok(Command {
+        action,
+        rectangle: Rectangle::new(from, through),
+    })
+}
+
+/// One command per line, such as `toggle 0,0 through 999,0`.
+pub fn parse(input: &str) -> Result<Vec<Command>> {
+    (1..)
+        .zip(input.lines())
+        .map(|(number, line)| parse_command(input, number, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let commands =
+            parse("turn on 0,0 through 999,999\ntoggle 499,3 through 500,1000000").unwrap();
+        assert_eq!(
+            commands,
+            vec![
+                Command {
+                    action: Action::On,
+                    rectangle: Rectangle::new((0, 0), (999, 999)),
+                },
+                Command {
+                    action: Action::Toggle,
+                    rectangle: Rectangle::new((499, 3), (500, 1_000_000)),
+                },
+            ]
+        );
+        assert!(parse("switch 0,0 through 1,1").is_err());
+
+        let error = parse("toggle 0,0 through 1,1\ntoggle 0,0 through 1,99999999999999999999")
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((error.line(), error.column()), (2, 22));
+
+        let last = usize::MAX - 1;
+        let commands = parse(&format!("toggle {0},{0} through {0},{0}", last)).unwrap();
+        assert_eq!(
+            commands[0].rectangle,
+            Rectangle::new((last, last), (last, last))
+        );
+        let error = parse(&format!("toggle 0,0 through 1,{}", usize::MAX))
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((error.line(), error.column()), (1, 22));
+    }
+}
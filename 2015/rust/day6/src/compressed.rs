@@ -0,0 +1,134 @@
+use advent_support::Grid;
+use anyhow::{anyhow, Result};
+
+use crate::command::Rectangle;
+
+/// Sorted, deduplicated edges, where each rectangle starts and where the one past its end is.
+fn edges(mut edges: Vec<usize>) -> Vec<usize> {
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+/// Lights grouped into blocks that every command either covers completely or leaves alone, by
+/// cutting the plane at each edge of each command's rectangle. The size depends on how many
+/// commands there are, not how far their coordinates reach.
+#[derive(Debug)]
+pub struct CompressedGrid<T> {
+    xs: Vec<usize>,
+    ys: Vec<usize>,
+    /// Block `(x, y)` spans columns `xs[x]..xs[x + 1]` and rows `ys[y]..ys[y + 1]`.
+    blocks: Grid<T>,
+}
+
+impl<T: Clone> CompressedGrid<T> {
+    /// A grid cut up for `rectangles`, with every light set to `value`. No rectangle may reach
+    /// `usize::MAX`, as there would be no edge past it.
+    pub fn new(rectangles: &[Rectangle], value: T) -> Self {
+        let xs = edges(
+            rectangles
+                .iter()
+                .flat_map(|r| IntoIterator::into_iter([r.left, r.right + 1]))
+                .collect(),
+        );
+        let ys = edges(
+            rectangles
+                .iter()
+                .flat_map(|r| IntoIterator::into_iter([r.top, r.bottom + 1]))
+                .collect(),
+        );
+        let blocks = Grid::new(
+            xs.len().saturating_sub(1),
+            ys.len().saturating_sub(1),
+            value,
+        );
+        Self { xs, ys, blocks }
+    }
+}
+
+impl<T> CompressedGrid<T> {
+    /// Apply `change` to every light in `rectangle`, which must be one the grid was built for.
+    pub fn update(&mut self, rectangle: &Rectangle, mut change: impl FnMut(&mut T)) {
+        let column = |x| {
+            self.xs
+                .binary_search(&x)
+                .expect("an edge of a known rectangle")
+        };
+        let row = |y| {
+            self.ys
+                .binary_search(&y)
+                .expect("an edge of a known rectangle")
+        };
+        let (left, right) = (column(rectangle.left), column(rectangle.right + 1));
+        let (top, bottom) = (row(rectangle.top), row(rectangle.bottom + 1));
+
+        for y in top..bottom {
+            for x in left..right {
+                change(&mut self.blocks[(x, y)]);
+            }
+        }
+    }
+
+    /// Sum `value` over every light any rectangle covers. Lights outside all of them are not
+    /// counted, so `value` of the starting state should be zero.
+    ///
+    /// A block's area always fits in a `u128`, but weighted and summed it may not, which is an
+    /// error.
+    pub fn total(&self, value: impl Fn(&T) -> u64) -> Result<u128> {
+        self.blocks
+            .positions()
+            .try_fold(0_u128, |total, ((x, y), block)| {
+                let width = (self.xs[x + 1] - self.xs[x]) as u128;
+                let height = (self.ys[y + 1] - self.ys[y]) as u128;
+                (width * height)
+                    .checked_mul(value(block).into())
+                    .and_then(|lights| total.checked_add(lights))
+            })
+            .ok_or_else(|| anyhow!("the total does not fit in a u128"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let rectangles = [
+            Rectangle {
+                left: 0,
+                top: 0,
+                right: 9,
+                bottom: 9,
+            },
+            Rectangle {
+                left: 5,
+                top: 2,
+                right: 1_000_004,
+                bottom: 2,
+            },
+        ];
+        let mut grid = CompressedGrid::new(&rectangles, 0_u64);
+        assert_eq!((grid.blocks.width(), grid.blocks.height()), (3, 3));
+
+        grid.update(&rectangles[0], |light| *light += 1);
+        assert_eq!(grid.total(|light| *light).unwrap(), 100);
+        grid.update(&rectangles[1], |light| *light += 2);
+        assert_eq!(grid.total(|light| *light).unwrap(), 100 + 2 * 1_000_000);
+        assert_eq!(grid.total(|light| (*light == 3) as u64).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_coordinate_limit() {
+        // As far as parsing allows, so a single block of (usize::MAX)² lights.
+        let last = usize::MAX - 1;
+        let rectangles = [Rectangle::new((0, 0), (last, last))];
+        let mut grid = CompressedGrid::new(&rectangles, 0_u64);
+        grid.update(&rectangles[0], |light| *light += 1);
+        let area = usize::MAX as u128 * usize::MAX as u128;
+        assert_eq!(grid.total(|light| *light).unwrap(), area);
+
+        // Twice that is more than a u128 holds.
+        assert!(grid.total(|light| *light * 2).is_err());
+    }
+}
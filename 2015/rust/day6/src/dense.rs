@@ -0,0 +1,190 @@
+use std::fmt;
+
+use advent_support::{gray, Grid, Image};
+use anyhow::{bail, Result};
+
+use crate::command::Command;
+use crate::rule::LightRule;
+
+/// Every light of a 1000x1000 grid.
+pub struct LightState<'a, R: LightRule> {
+    rule: &'a R,
+    lights: Grid<R::Light>,
+}
+
+impl<'a, R: LightRule> LightState<'a, R> {
+    pub fn new(rule: &'a R) -> Self {
+        Self {
+            rule,
+            lights: Grid::new(1_000, 1_000, rule.initial()),
+        }
+    }
+
+    pub fn update(&mut self, command: &Command) -> Result<()> {
+        let rectangle = &command.rectangle;
+        if !self.lights.contains(rectangle.left, rectangle.top)
+            || !self.lights.contains(rectangle.right, rectangle.bottom)
+        {
+            bail!("{:?} is outside the 1000x1000 grid", rectangle);
+        }
+        for row in rectangle.top..=rectangle.bottom {
+            for column in rectangle.left..=rectangle.right {
+                self.rule
+                    .apply(command.action, &mut self.lights[(column, row)]);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn total(&self) -> u64 {
+        self.lights.iter().map(|light| self.rule.value(light)).sum()
+    }
+
+    /// The value of the brightest light.
+    pub fn peak(&self) -> u64 {
+        self.lights
+            .iter()
+            .map(|light| self.rule.value(light))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// One pixel per light, shaded from black at zero up to white at `peak`.
+    pub fn image(&self, peak: u64) -> Image {
+        Image::from_grid(&self.lights, 1, |light| gray(self.rule.value(light), peak))
+    }
+
+    #[cfg(test)]
+    fn count(&self, value: u64) -> usize {
+        self.lights
+            .iter()
+            .filter(|light| self.rule.value(light) == value)
+            .count()
+    }
+}
+
+/// Lit lights as `O`, dark ones as `X`.
+impl<'a, R: LightRule> fmt::Display for LightState<'a, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.lights.rows() {
+            for light in row {
+                let glyph = if self.rule.value(light) > 0 { 'O' } else { 'X' };
+                write!(f, "{}", glyph)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::{Action, Rectangle};
+    use crate::rule::{Brightness, OnOff};
+
+    fn command(action: Action, from: (usize, usize), through: (usize, usize)) -> Command {
+        Command {
+            action,
+            rectangle: Rectangle::new(from, through),
+        }
+    }
+
+    fn run<'a, R: LightRule>(rule: &'a R, commands: &[Command]) -> LightState<'a, R> {
+        let mut state = LightState::new(rule);
+        for command in commands {
+            state.update(command).unwrap();
+        }
+        state
+    }
+
+    #[test]
+    fn test_all_lights_is_1_million() {
+        let all_on = [command(Action::On, (0, 0), (999, 999))];
+        assert_eq!(run(&OnOff, &all_on).count(1), 1_000_000);
+        assert_eq!(run(&Brightness, &all_on).count(1), 1_000_000);
+    }
+
+    #[test]
+    fn test_all_lights_is_1_million_brightness() {
+        let state = run(&Brightness, &[command(Action::On, (0, 0), (999, 999))]);
+        assert_eq!(state.total(), 1_000_000);
+    }
+
+    #[test]
+    fn test_all_lights_twice_is_2_million_brightness() {
+        let state = run(
+            &Brightness,
+            &[
+                command(Action::On, (0, 0), (999, 999)),
+                command(Action::On, (0, 0), (999, 999)),
+            ],
+        );
+        assert_eq!(state.total(), 2_000_000);
+    }
+
+    #[test]
+    fn test_toggle_all_lights_twice_is_4_million_brightness() {
+        let state = run(
+            &Brightness,
+            &[
+                command(Action::Toggle, (0, 0), (999, 999)),
+                command(Action::Toggle, (0, 0), (999, 999)),
+            ],
+        );
+        assert_eq!(state.total(), 4_000_000);
+    }
+
+    #[test]
+    fn test_toggle_all_lights_then_off_is_3_million_brightness() {
+        let state = run(
+            &Brightness,
+            &[
+                command(Action::Toggle, (0, 0), (999, 999)),
+                command(Action::Toggle, (0, 0), (999, 999)),
+                command(Action::Off, (0, 0), (999, 999)),
+            ],
+        );
+        assert_eq!(state.total(), 3_000_000);
+    }
+
+    #[test]
+    fn test_first_row_on() {
+        let first_row = [command(Action::On, (0, 0), (999, 0))];
+        assert_eq!(run(&OnOff, &first_row).count(1), 1_000);
+        assert_eq!(run(&Brightness, &first_row).count(1), 1_000);
+    }
+
+    #[test]
+    fn test_middle_rectangle_is_off() {
+        let commands = [
+            command(Action::On, (0, 0), (999, 999)),
+            command(Action::Off, (499, 499), (500, 500)),
+        ];
+        assert_eq!(run(&OnOff, &commands).count(1), 1_000_000 - 4);
+        assert_eq!(run(&Brightness, &commands).count(1), 1_000_000 - 4);
+    }
+
+    #[test]
+    fn test_image() {
+        let state = run(
+            &Brightness,
+            &[
+                command(Action::On, (0, 0), (1, 0)),
+                command(Action::Toggle, (1, 0), (1, 0)),
+            ],
+        );
+        assert_eq!(state.peak(), 3);
+        let image = state.image(state.peak());
+        assert_eq!((image.width(), image.height()), (1_000, 1_000));
+        assert_eq!(&image.pixels()[..3], &[85, 255, 0]);
+    }
+
+    #[test]
+    fn test_outside_grid() {
+        let mut state = LightState::new(&OnOff);
+        assert!(state
+            .update(&command(Action::On, (0, 0), (1_000, 0)))
+            .is_err());
+    }
+}
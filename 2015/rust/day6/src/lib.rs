@@ -0,0 +1,201 @@
+extern crate pest;
+#[macro_use]
+extern crate pest_derive;
+
+use std::path::Path;
+
+use advent_support::{Animation, Solution};
+use anyhow::Result;
+
+pub mod command;
+pub mod compressed;
+pub mod dense;
+pub mod rule;
+
+use compressed::CompressedGrid;
+use dense::LightState;
+use rule::{Brightness, LightRule, OnOff};
+
+/// Where the lights are kept while the commands run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// Every light of a 1000x1000 grid, see [`dense::LightState`].
+    Dense,
+    /// Blocks of lights that always change together, see [`compressed::CompressedGrid`].
+    Compressed,
+}
+
+/// Run every command in `input` under `rule` and add up the value of all the lights.
+pub fn run<R: LightRule>(input: &str, rule: &R, backend: Backend) -> Result<u128> {
+    let commands = command::parse(input)?;
+    match backend {
+        Backend::Dense => {
+            let mut state = LightState::new(rule);
+            for command in &commands {
+                state.update(command)?;
+            }
+            Ok(state.total().into())
+        }
+        Backend::Compressed => {
+            let rectangles: Vec<_> = commands.iter().map(|command| command.rectangle).collect();
+            let mut grid = CompressedGrid::new(&rectangles, rule.initial());
+            for command in &commands {
+                grid.update(&command.rectangle, |light| {
+                    rule.apply(command.action, light)
+                });
+            }
+            grid.total(|light| rule.value(light))
+        }
+    }
+}
+
+/// Draw the lights after the last command as a PNG or PPM, or after every command as a GIF,
+/// depending on the extension of `path`. Brightness is scaled so the brightest light the commands
+/// ever reach is white.
+pub fn render<R: LightRule>(input: &str, rule: &R, path: &Path) -> Result<()> {
+    let commands = command::parse(input)?;
+    let mut state = LightState::new(rule);
+    if path.extension().is_some_and(|extension| extension == "gif") {
+        let mut peak = 0;
+        for command in &commands {
+            state.update(command)?;
+            peak = peak.max(state.peak());
+        }
+
+        let mut state = LightState::new(rule);
+        let mut animation = Animation::create(path, 1_000, 1_000, 5)?;
+        animation.add(&state.image(peak))?;
+        for command in &commands {
+            state.update(command)?;
+            animation.add(&state.image(peak))?;
+        }
+        Ok(())
+    } else {
+        for command in &commands {
+            state.update(command)?;
+        }
+        state.image(state.peak()).save(path)
+    }
+}
+
+pub fn lights_on(input: &str, backend: Backend) -> Result<u128> {
+    run(input, &OnOff, backend)
+}
+
+pub fn total_brightness(input: &str, backend: Backend) -> Result<u128> {
+    run(input, &Brightness, backend)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(lights_on(input, Backend::Compressed)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(total_brightness(input, Backend::Compressed)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use command::Action;
+
+    /// Commands with corners from a fixed pseudo-random sequence, all inside the dense grid.
+    fn commands(count: usize) -> String {
+        let mut seed: u64 = 2015;
+        let mut next = |limit: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) % limit
+        };
+        let actions = ["turn on", "turn off", "toggle"];
+        (0..count)
+            .map(|_| {
+                let action = actions[next(3) as usize];
+                let (left, top) = (next(1_000), next(1_000));
+                let (right, bottom) = (left + next(1_000 - left), top + next(1_000 - top));
+                format!("{} {},{} through {},{}", action, left, top, right, bottom)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_backends_agree() {
+        let input = commands(40);
+        for rule in &[lights_on, total_brightness] {
+            assert_eq!(
+                rule(&input, Backend::Dense).unwrap(),
+                rule(&input, Backend::Compressed).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_grid() {
+        let input = "turn on 0,0 through 99999,99999\n\
+                     toggle 50000,0 through 149999,0\n\
+                     turn off 0,0 through 0,0";
+        assert_eq!(
+            lights_on(input, Backend::Compressed).unwrap(),
+            10_000_000_000 - 50_000 + 50_000 - 1
+        );
+        assert_eq!(
+            total_brightness(input, Backend::Compressed).unwrap(),
+            10_000_000_000 + 2 * 100_000 - 1
+        );
+        assert!(lights_on(input, Backend::Dense).is_err());
+    }
+
+    #[test]
+    fn test_coordinate_limit() {
+        let last = usize::MAX - 1;
+        let input = format!("turn on 0,0 through {0},{0}", last);
+        let area = usize::MAX as u128 * usize::MAX as u128;
+        assert_eq!(lights_on(&input, Backend::Compressed).unwrap(), area);
+        assert_eq!(total_brightness(&input, Backend::Compressed).unwrap(), area);
+        // Toggling adds two brightness to each light, more than a u128 holds.
+        let input = format!("toggle 0,0 through {0},{0}", last);
+        assert!(total_brightness(&input, Backend::Compressed).is_err());
+    }
+
+    /// Lights that only count once they have been switched on at least twice.
+    struct Stubborn;
+
+    impl LightRule for Stubborn {
+        type Light = u8;
+
+        fn initial(&self) -> u8 {
+            0
+        }
+
+        fn apply(&self, action: Action, light: &mut u8) {
+            *light = match action {
+                Action::On => (*light + 1).min(2),
+                Action::Off => 0,
+                Action::Toggle => *light,
+            };
+        }
+
+        fn value(&self, light: &u8) -> u64 {
+            (*light == 2) as u64
+        }
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        let input = "turn on 0,0 through 9,9\nturn on 5,5 through 14,14\ntoggle 0,0 through 99,99";
+        assert_eq!(run(input, &Stubborn, Backend::Dense).unwrap(), 25);
+        assert_eq!(run(input, &Stubborn, Backend::Compressed).unwrap(), 25);
+
+        let input = commands(40);
+        assert_eq!(
+            run(&input, &Stubborn, Backend::Dense).unwrap(),
+            run(&input, &Stubborn, Backend::Compressed).unwrap()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+use crate::command::Action;
+
+/// What a command does to a single light, and how much each light counts towards the answer.
+pub trait LightRule {
+    type Light: Clone + fmt::Debug;
+
+    /// Every light before any command runs. Its `value` must be zero.
+    fn initial(&self) -> Self::Light;
+    fn apply(&self, action: Action, light: &mut Self::Light);
+    fn value(&self, light: &Self::Light) -> u64;
+}
+
+/// Lights are simply on or off, and the answer is how many are on.
+#[derive(Clone, Copy, Debug)]
+pub struct OnOff;
+
+impl LightRule for OnOff {
+    type Light = bool;
+
+    fn initial(&self) -> bool {
+        false
+    }
+
+    fn apply(&self, action: Action, light: &mut bool) {
+        *light = match action {
+            Action::Toggle => !*light,
+            Action::On => true,
+            Action::Off => false,
+        };
+    }
+
+    fn value(&self, light: &bool) -> u64 {
+        *light as u64
+    }
+}
+
+/// Each light has a brightness that never drops below zero, and the answer is the total.
+#[derive(Clone, Copy, Debug)]
+pub struct Brightness;
+
+impl LightRule for Brightness {
+    type Light = u32;
+
+    fn initial(&self) -> u32 {
+        0
+    }
+
+    fn apply(&self, action: Action, light: &mut u32) {
+        match action {
+            Action::Toggle => {
+                *light += 2;
+            }
+            Action::On => {
+                *light += 1;
+            }
+            Action::Off => {
+                *light = light.saturating_sub(1);
+            }
+        };
+    }
+
+    fn value(&self, light: &u32) -> u64 {
+        *light as u64
+    }
+}
@@ -1,28 +1,11 @@
-use std::collections::HashMap;
-
-#[macro_use]
-extern crate pest_derive;
-
+use advent_support::{read_input_string, Solution};
 use anyhow::Result;
 
-mod wires;
-use wires::{eval, load_wire_connections, Operation};
+use aoc2015_day7::Day7;
 
 fn main() -> Result<()> {
-    let mut wire_connections: HashMap<String, Operation> = HashMap::new();
-
-    load_wire_connections(&mut wire_connections)?;
-
-    let mut wire_state: HashMap<String, u16> = HashMap::new();
-    eval(&wire_connections, &mut wire_state, "a")?;
-    let a_value = wire_state.get("a").unwrap();
-    println!("{:?}", a_value);
-
-    let mut wire_state: HashMap<String, u16> = HashMap::new();
-    wire_state.insert("b".to_string(), *a_value);
-    eval(&wire_connections, &mut wire_state, "a")?;
-    let a_value = wire_state.get("a").unwrap();
-    println!("{:?}", a_value);
+    let input = read_input_string()?;
+    println!("{}", Day7.part2(&input)?);
 
     Ok(())
 }
@@ -0,0 +1,25 @@
+use std::collections::HashMap;
+
+use advent_support::read_input;
+use anyhow::Result;
+
+use aoc2015_day7::circuit::{Netlist, Simulation};
+use aoc2015_day7::wires::{load_wire_connections, to_dot, Operation};
+
+/// Print the circuit read from stdin as a DOT graph, with wire values when it can be evaluated.
+fn main() -> Result<()> {
+    let input = read_input::<String>()?.join("\n");
+    let mut wire_connections: HashMap<String, Operation> = HashMap::new();
+    load_wire_connections(&input, &mut wire_connections)?;
+
+    let state = match Netlist::compile(&wire_connections) {
+        Ok(netlist) => Some(Simulation::new(&netlist).state()),
+        Err(error) => {
+            eprintln!("not annotating values: {}", error);
+            None
+        }
+    };
+    print!("{}", to_dot(&wire_connections, state.as_ref()));
+
+    Ok(())
+}
@@ -0,0 +1,367 @@
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+use crate::wires::{BinaryOp, NameOrNumber, Operation, UnaryOp};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The wires form a loop, listed in signal order with the first wire repeated at the end.
+    Cycle(Vec<String>),
+    UndefinedWire {
+        wire: String,
+        used_by: String,
+    },
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::Cycle(wires) => write!(f, "cycle through wires {}", wires.join(" -> ")),
+            CircuitError::UndefinedWire { wire, used_by } => {
+                write!(f, "wire {} is used by {} but never defined", wire, used_by)
+            }
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+#[derive(Clone, Copy, Debug)]
+enum Signal {
+    Wire(usize),
+    Constant(u16),
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Gate {
+    Unary(UnaryOp, Signal),
+    Binary(BinaryOp, Signal, Signal),
+}
+
+impl Gate {
+    fn inputs(&self) -> impl Iterator<Item = usize> {
+        let signals = match *self {
+            Gate::Unary(_, signal) => [Some(signal), None],
+            Gate::Binary(_, first, second) => [Some(first), Some(second)],
+        };
+        IntoIterator::into_iter(signals)
+            .flatten()
+            .filter_map(|signal| match signal {
+                Signal::Wire(index) => Some(index),
+                Signal::Constant(_) => None,
+            })
+    }
+}
+
+/// A circuit with every wire resolved to an index and sorted so each wire comes after all of
+/// the wires it reads.
+#[derive(Debug)]
+pub struct Netlist {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+    gates: Vec<Gate>,
+    order: Vec<usize>,
+    dependents: Vec<Vec<usize>>,
+}
+
+impl Netlist {
+    pub fn compile(connections: &HashMap<String, Operation>) -> Result<Self, CircuitError> {
+        let mut names: Vec<String> = connections.keys().cloned().collect();
+        names.sort();
+        let indices: HashMap<String, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.clone(), index))
+            .collect();
+
+        let resolve = |operand: &NameOrNumber, used_by: &str| match operand {
+            NameOrNumber::Number(value) => Ok(Signal::Constant(*value)),
+            NameOrNumber::Name(name) => indices
+                .get(name)
+                .map(|index| Signal::Wire(*index))
+                .ok_or_else(|| CircuitError::UndefinedWire {
+                    wire: name.clone(),
+                    used_by: used_by.to_string(),
+                }),
+        };
+        let gates = names
+            .iter()
+            .map(|name| match &connections[name] {
+                Operation::UnaryOperation((op, operand)) => {
+                    Ok(Gate::Unary(*op, resolve(operand, name)?))
+                }
+                Operation::BinaryOperation((op, first, second)) => Ok(Gate::Binary(
+                    *op,
+                    resolve(first, name)?,
+                    resolve(second, name)?,
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut dependents = vec![Vec::new(); names.len()];
+        let mut pending = vec![0; names.len()];
+        for (index, gate) in gates.iter().enumerate() {
+            for input in gate.inputs() {
+                dependents[input].push(index);
+                pending[index] += 1;
+            }
+        }
+
+        // Kahn's algorithm: a wire is ready once everything it reads has been placed.
+        let mut ready: VecDeque<usize> = (0..names.len()).filter(|i| pending[*i] == 0).collect();
+        let mut order = Vec::with_capacity(names.len());
+        while let Some(index) = ready.pop_front() {
+            order.push(index);
+            for &dependent in &dependents[index] {
+                pending[dependent] -= 1;
+                if pending[dependent] == 0 {
+                    ready.push_back(dependent);
+                }
+            }
+        }
+
+        let netlist = Self {
+            names,
+            indices,
+            gates,
+            order,
+            dependents,
+        };
+        if netlist.order.len() < netlist.names.len() {
+            return Err(CircuitError::Cycle(netlist.find_cycle(&pending)));
+        }
+        Ok(netlist)
+    }
+
+    /// Every wire left with inputs pending is in a cycle or downstream of one, and always has a
+    /// pending input of its own, so walking back along pending inputs must come round in a loop.
+    fn find_cycle(&self, pending: &[usize]) -> Vec<String> {
+        let mut path = Vec::new();
+        let mut current = (0..pending.len())
+            .find(|i| pending[*i] > 0)
+            .expect("an unplaced wire");
+        while !path.contains(&current) {
+            path.push(current);
+            current = self.gates[current]
+                .inputs()
+                .find(|input| pending[*input] > 0)
+                .expect("an unplaced input");
+        }
+        let start = path.iter().position(|index| *index == current).unwrap();
+        let mut cycle: Vec<String> = path[start..]
+            .iter()
+            .rev()
+            .map(|index| self.names[*index].clone())
+            .collect();
+        cycle.push(cycle[0].clone());
+        cycle
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Wire names in evaluation order.
+    pub fn wires(&self) -> impl Iterator<Item = &str> {
+        self.order
+            .iter()
+            .map(move |index| self.names[*index].as_str())
+    }
+
+    fn index(&self, wire: &str) -> Result<usize, CircuitError> {
+        self.indices
+            .get(wire)
+            .copied()
+            .ok_or_else(|| CircuitError::UndefinedWire {
+                wire: wire.to_string(),
+                used_by: "the caller".to_string(),
+            })
+    }
+
+    fn evaluate_gate(&self, index: usize, values: &[u16]) -> u16 {
+        let value = |signal| match signal {
+            Signal::Wire(index) => values[index],
+            Signal::Constant(value) => value,
+        };
+        match self.gates[index] {
+            Gate::Unary(UnaryOp::EVAL, signal) => value(signal),
+            Gate::Unary(UnaryOp::NOT, signal) => !value(signal),
+            Gate::Binary(op, first, second) => {
+                let (first, second) = (value(first), value(second));
+                match op {
+                    BinaryOp::AND => first & second,
+                    BinaryOp::OR => first | second,
+                    BinaryOp::LSHIFT => first.overflowing_shl(second as u32).0,
+                    BinaryOp::RSHIFT => first.overflowing_shr(second as u32).0,
+                }
+            }
+        }
+    }
+}
+
+/// The value on every wire of a [`Netlist`], with some wires optionally forced to a fixed value.
+#[derive(Debug)]
+pub struct Simulation<'a> {
+    netlist: &'a Netlist,
+    values: Vec<u16>,
+    overrides: HashMap<usize, u16>,
+}
+
+impl<'a> Simulation<'a> {
+    pub fn new(netlist: &'a Netlist) -> Self {
+        let mut simulation = Self {
+            netlist,
+            values: vec![0; netlist.len()],
+            overrides: HashMap::new(),
+        };
+        for &index in &netlist.order {
+            simulation.update(index);
+        }
+        simulation
+    }
+
+    pub fn get(&self, wire: &str) -> Option<u16> {
+        self.netlist
+            .indices
+            .get(wire)
+            .map(|index| self.values[*index])
+    }
+
+    /// Every wire and its value.
+    pub fn state(&self) -> HashMap<String, u16> {
+        self.netlist
+            .names
+            .iter()
+            .cloned()
+            .zip(self.values.iter().copied())
+            .collect()
+    }
+
+    /// Force `wire` to `value`, ignoring its own inputs, and bring everything downstream of it
+    /// up to date. Returns how many wires were recomputed.
+    pub fn set_override(&mut self, wire: &str, value: u16) -> Result<usize, CircuitError> {
+        let index = self.netlist.index(wire)?;
+        self.overrides.insert(index, value);
+        Ok(self.propagate(index))
+    }
+
+    /// Let `wire` follow its inputs again. Returns how many wires were recomputed.
+    pub fn clear_override(&mut self, wire: &str) -> Result<usize, CircuitError> {
+        let index = self.netlist.index(wire)?;
+        self.overrides.remove(&index);
+        Ok(self.propagate(index))
+    }
+
+    fn update(&mut self, index: usize) {
+        self.values[index] = match self.overrides.get(&index) {
+            Some(value) => *value,
+            None => self.netlist.evaluate_gate(index, &self.values),
+        };
+    }
+
+    /// Recompute `start` and everything that reads from it, directly or not, in netlist order.
+    fn propagate(&mut self, start: usize) -> usize {
+        let mut stale = vec![false; self.netlist.len()];
+        let mut stack = vec![start];
+        while let Some(index) = stack.pop() {
+            if !stale[index] {
+                stale[index] = true;
+                stack.extend(&self.netlist.dependents[index]);
+            }
+        }
+
+        let mut count = 0;
+        for &index in &self.netlist.order {
+            if stale[index] {
+                self.update(index);
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wires::load_wire_connections;
+
+    const EXAMPLE: &str = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+
+    fn compile(input: &str) -> Result<Netlist, CircuitError> {
+        let mut connections = HashMap::new();
+        load_wire_connections(input, &mut connections).unwrap();
+        Netlist::compile(&connections)
+    }
+
+    #[test]
+    fn test_example() {
+        let netlist = compile(EXAMPLE).unwrap();
+        let simulation = Simulation::new(&netlist);
+        let expected = [
+            ("d", 72),
+            ("e", 507),
+            ("f", 492),
+            ("g", 114),
+            ("h", 65412),
+            ("i", 65079),
+            ("x", 123),
+            ("y", 456),
+        ];
+        let expected: HashMap<String, u16> = expected
+            .iter()
+            .map(|(wire, value)| (wire.to_string(), *value))
+            .collect();
+        assert_eq!(simulation.state(), expected);
+    }
+
+    #[test]
+    fn test_order() {
+        let netlist = compile("d -> a\nc OR 1 -> d\n3 -> c\nc AND d -> e").unwrap();
+        let order: Vec<&str> = netlist.wires().collect();
+        assert_eq!(order, vec!["c", "d", "a", "e"]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            compile("b -> a\nNOT c -> b\nb AND 1 -> c\n1 -> d").unwrap_err(),
+            CircuitError::Cycle(vec!["c".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            compile("x -> a").unwrap_err(),
+            CircuitError::UndefinedWire {
+                wire: "x".to_string(),
+                used_by: "a".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_override() {
+        let netlist = compile(EXAMPLE).unwrap();
+        let mut simulation = Simulation::new(&netlist);
+
+        // Only x and the four wires reading it change.
+        assert_eq!(simulation.set_override("x", 1), Ok(5));
+        assert_eq!(simulation.get("d"), Some(0));
+        assert_eq!(simulation.get("h"), Some(65534));
+        assert_eq!(simulation.get("g"), Some(114));
+
+        assert_eq!(simulation.clear_override("x"), Ok(5));
+        assert_eq!(simulation.get("d"), Some(72));
+        assert!(simulation.set_override("nope", 1).is_err());
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+#[macro_use]
+extern crate pest_derive;
+
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+
+pub mod circuit;
+pub mod wires;
+use circuit::{Netlist, Simulation};
+use wires::{load_wire_connections, Operation};
+
+pub fn compile(input: &str) -> Result<Netlist> {
+    let mut wire_connections: HashMap<String, Operation> = HashMap::new();
+    load_wire_connections(input, &mut wire_connections)?;
+    Ok(Netlist::compile(&wire_connections)?)
+}
+
+fn signal_on_a(simulation: &Simulation) -> Result<u16> {
+    simulation
+        .get("a")
+        .ok_or_else(|| anyhow!("wire a has no signal"))
+}
+
+/// The signal on `a` after feeding the first answer for `a` back in on `b`.
+pub fn rewired_signal_on_a(netlist: &Netlist) -> Result<u16> {
+    let mut simulation = Simulation::new(netlist);
+    let a_value = signal_on_a(&simulation)?;
+    simulation.set_override("b", a_value)?;
+    signal_on_a(&simulation)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let netlist = compile(input)?;
+        Ok(signal_on_a(&Simulation::new(&netlist))?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(rewired_signal_on_a(&compile(input)?)?.to_string())
+    }
+}
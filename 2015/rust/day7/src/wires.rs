@@ -0,0 +1,507 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::rc::Rc;
+
+use anyhow::Result;
+use pest::{iterators::Pair, Parser};
+use petgraph::dot::{Config, Dot};
+use petgraph::graph::{DiGraph, NodeIndex};
+
+use crate::circuit::CircuitError;
+
+#[derive(Parser)]
+#[grammar = "wireA.pest"]
+pub struct Wire;
+
+#[derive(Clone, Debug)]
+pub enum NameOrNumber {
+    Name(String),
+    Number(u16),
+}
+
+impl From<NameOrNumber> for String {
+    fn from(val: NameOrNumber) -> Self {
+        match val {
+            NameOrNumber::Name(name) => name,
+            NameOrNumber::Number(_) => unimplemented!("string from number"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinaryOp {
+    AND,
+    OR,
+    LSHIFT,
+    RSHIFT,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnaryOp {
+    EVAL,
+    NOT,
+}
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    BinaryOperation((BinaryOp, NameOrNumber, NameOrNumber)),
+    UnaryOperation((UnaryOp, NameOrNumber)),
+}
+
+/*
+  Wire state. A map from name to value.
+
+  Parse line
+    could be
+     - signal to wire
+     - binary op with 2 sources and a destination
+     - unary op with 1 source and a destination
+
+  Signal: Put value in wire name
+  Binary op: Apply value from both wires to destination wire
+  Unary op: apply value from wire to destination
+*/
+fn parse_signal(parser: Pair<Rule>) -> Result<Operation> {
+    //println!("Signal {:?}", parser);
+    let name_or_number = parse_name(parser.into_inner().next().expect("valid number"))?;
+    Ok(Operation::UnaryOperation((UnaryOp::EVAL, name_or_number)))
+}
+
+fn parse_unary_operation(parser: Pair<Rule>) -> Result<Operation> {
+    //println!("Unary op {:?}", parser);
+    let mut inner = parser.into_inner();
+    let op = match inner.next().expect("valid unary op").as_str() {
+        "NOT" => UnaryOp::NOT,
+        r => panic!("Unhandled Unary op {:?}", r),
+    };
+    let name_or_number = parse_name(inner.next().expect("a valid name or number"))?;
+    Ok(Operation::UnaryOperation((op, name_or_number)))
+}
+
+fn parse_binary_operation(parser: Pair<Rule>) -> Result<Operation> {
+    let mut inner = parser.into_inner();
+    let name_or_number = inner
+        .next()
+        .expect("valid name or number")
+        .into_inner()
+        .next()
+        .expect("parse valid name or number");
+    let operand1 = parse_name(name_or_number)?;
+    let op = match inner.next().expect("valid binary op").as_str() {
+        "AND" => BinaryOp::AND,
+        "OR" => BinaryOp::OR,
+        "LSHIFT" => BinaryOp::LSHIFT,
+        "RSHIFT" => BinaryOp::RSHIFT,
+        r => panic!("Unhandled Unary op {:?}", r),
+    };
+    let name_or_number = inner
+        .next()
+        .expect("valid name or number")
+        .into_inner()
+        .next()
+        .expect("parse valid name or number");
+    let operand2 = parse_name(name_or_number)?;
+    Ok(Operation::BinaryOperation((op, operand1, operand2)))
+}
+
+fn parse_operation(parser: Pair<Rule>) -> Result<Operation> {
+    let mut inner = parser.into_inner();
+    let op = inner.next().expect("valid op");
+    match op.as_rule() {
+        Rule::signal => parse_signal(op),
+        Rule::unary_operation => parse_unary_operation(op),
+        Rule::binary_operation => parse_binary_operation(op),
+        r => {
+            panic!("Unhandled rule {:?}", r);
+        }
+    }
+}
+
+fn parse_name(parser: Pair<Rule>) -> Result<NameOrNumber> {
+    match parser.as_rule() {
+        Rule::name => Ok(NameOrNumber::Name(parser.as_str().to_string())),
+        Rule::number => {
+            let number = parser.as_str().parse().expect("a number");
+            Ok(NameOrNumber::Number(number))
+        }
+        Rule::name_or_number => parse_name(parser.into_inner().next().unwrap()),
+        r => panic!("Unhandled rule as name: {:?}", r),
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Operation, String)> {
+    let result = Wire::parse(Rule::circuit_part, input)?.next().unwrap();
+    let mut circuit_parts = result.into_inner();
+    let operation = circuit_parts.next().expect("valid operation");
+    let op = parse_operation(operation)?;
+    let _ = circuit_parts.next().expect("->");
+    let name_part = circuit_parts.next().expect("valid name");
+    let name = parse_name(name_part)?;
+
+    Ok((op, name.into()))
+}
+
+pub fn load_wire_connections(
+    input: &str,
+    connections: &mut HashMap<String, Operation>,
+) -> Result<()> {
+    for line in input.lines() {
+        let (operation, wire_name) = parse(line)?;
+        // println!("{:?} {:?}", operation, wire_name);
+        connections.insert(wire_name, operation);
+    }
+
+    Ok(())
+}
+
+/// A node of the graph drawn by [`to_dot`].
+enum DotNode {
+    Wire(String, Option<u16>),
+    Gate(String),
+    Constant(u16),
+}
+
+impl DotNode {
+    fn attributes(&self) -> String {
+        let shape = match self {
+            DotNode::Wire(..) => "ellipse",
+            DotNode::Gate(_) => "box",
+            DotNode::Constant(_) => "plaintext",
+        };
+        format!("shape = {} ", shape)
+    }
+}
+
+impl fmt::Display for DotNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DotNode::Wire(name, None) => write!(f, "{}", name),
+            DotNode::Wire(name, Some(value)) => write!(f, "{} = {}", name, value),
+            DotNode::Gate(op) => write!(f, "{}", op),
+            DotNode::Constant(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Draw the circuit as a Graphviz DOT digraph. Wires are ellipses, labelled with their value
+/// from `state` when one is given, gates are boxes and constants are plain text leaves. Plain
+/// assignments connect straight to their wire without a gate.
+pub fn to_dot(
+    connections: &HashMap<String, Operation>,
+    state: Option<&HashMap<String, u16>>,
+) -> String {
+    let mut graph: DiGraph<DotNode, &str> = DiGraph::new();
+    let mut wires: HashMap<String, NodeIndex> = HashMap::new();
+
+    let mut names: Vec<&String> = connections.keys().collect();
+    names.sort();
+
+    let mut wire = |graph: &mut DiGraph<DotNode, &str>, name: &str| -> NodeIndex {
+        *wires.entry(name.to_string()).or_insert_with(|| {
+            let value = state.and_then(|state| state.get(name).copied());
+            graph.add_node(DotNode::Wire(name.to_string(), value))
+        })
+    };
+    for name in &names {
+        wire(&mut graph, name);
+    }
+
+    for name in names {
+        let target = wire(&mut graph, name);
+        let (gate, operands) = match &connections[name] {
+            Operation::UnaryOperation((UnaryOp::EVAL, operand)) => (None, vec![operand]),
+            Operation::UnaryOperation((op, operand)) => (Some(format!("{:?}", op)), vec![operand]),
+            Operation::BinaryOperation((op, first, second)) => {
+                (Some(format!("{:?}", op)), vec![first, second])
+            }
+        };
+        let target = match gate {
+            Some(gate) => {
+                let node = graph.add_node(DotNode::Gate(gate));
+                graph.add_edge(node, target, "");
+                node
+            }
+            None => target,
+        };
+        for operand in operands {
+            let source = match operand {
+                NameOrNumber::Name(source) => wire(&mut graph, source),
+                NameOrNumber::Number(value) => graph.add_node(DotNode::Constant(*value)),
+            };
+            graph.add_edge(source, target, "");
+        }
+    }
+
+    format!(
+        "{}",
+        Dot::with_attr_getters(
+            &graph,
+            &[Config::EdgeNoLabel],
+            &|_, _| String::new(),
+            &|_, (_, node)| node.attributes(),
+        )
+    )
+}
+
+/// The signal on a wire written in terms of the inputs left free. Subexpressions are shared, so
+/// a wire read in many places is only built once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Constant(u16),
+    Input(String),
+    Not(Rc<Expr>),
+    Binary(BinaryOp, Rc<Expr>, Rc<Expr>),
+}
+
+impl Expr {
+    /// Apply NOT, folding constants and cancelling a double NOT.
+    fn not(operand: Rc<Expr>) -> Rc<Expr> {
+        match &*operand {
+            Expr::Constant(value) => Rc::new(Expr::Constant(!value)),
+            Expr::Not(inner) => inner.clone(),
+            _ => Rc::new(Expr::Not(operand)),
+        }
+    }
+
+    /// Apply a binary gate, folding constants and dropping operations that cannot change the
+    /// other side, such as `x AND 65535`, `x OR 0` or a shift by zero.
+    fn binary(op: BinaryOp, first: Rc<Expr>, second: Rc<Expr>) -> Rc<Expr> {
+        use BinaryOp::*;
+
+        let constant = |value| Rc::new(Expr::Constant(value));
+        match (op, &*first, &*second) {
+            (_, Expr::Constant(a), Expr::Constant(b)) => constant(match op {
+                AND => a & b,
+                OR => a | b,
+                LSHIFT => a.overflowing_shl(*b as u32).0,
+                RSHIFT => a.overflowing_shr(*b as u32).0,
+            }),
+            (AND, Expr::Constant(0), _) | (AND, _, Expr::Constant(0)) => constant(0),
+            (OR, Expr::Constant(0xffff), _) | (OR, _, Expr::Constant(0xffff)) => constant(0xffff),
+            (AND, Expr::Constant(0xffff), _) | (OR, Expr::Constant(0), _) => second,
+            (AND, _, Expr::Constant(0xffff)) | (OR, _, Expr::Constant(0)) => first,
+            (LSHIFT, _, Expr::Constant(0)) | (RSHIFT, _, Expr::Constant(0)) => first,
+            (LSHIFT, Expr::Constant(0), _) | (RSHIFT, Expr::Constant(0), _) => first,
+            (AND, _, _) | (OR, _, _) if first == second => first,
+            _ => Rc::new(Expr::Binary(op, first, second)),
+        }
+    }
+
+    /// The free inputs this expression still depends on.
+    pub fn inputs(&self) -> BTreeSet<String> {
+        fn visit(expr: &Expr, seen: &mut HashSet<*const Expr>, inputs: &mut BTreeSet<String>) {
+            if !seen.insert(expr) {
+                return;
+            }
+            match expr {
+                Expr::Constant(_) => {}
+                Expr::Input(name) => {
+                    inputs.insert(name.clone());
+                }
+                Expr::Not(operand) => visit(operand, seen, inputs),
+                Expr::Binary(_, first, second) => {
+                    visit(first, seen, inputs);
+                    visit(second, seen, inputs);
+                }
+            }
+        }
+
+        let mut inputs = BTreeSet::new();
+        visit(self, &mut HashSet::new(), &mut inputs);
+        inputs
+    }
+}
+
+/// Written out in full, so a heavily shared expression can print far larger than it is.
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nested = |f: &mut fmt::Formatter<'_>, expr: &Expr| match expr {
+            Expr::Binary(..) => write!(f, "({})", expr),
+            _ => write!(f, "{}", expr),
+        };
+        match self {
+            Expr::Constant(value) => write!(f, "{}", value),
+            Expr::Input(name) => write!(f, "{}", name),
+            Expr::Not(operand) => {
+                write!(f, "NOT ")?;
+                nested(f, operand)
+            }
+            Expr::Binary(op, first, second) => {
+                nested(f, first)?;
+                write!(f, " {:?} ", op)?;
+                nested(f, second)
+            }
+        }
+    }
+}
+
+struct Symbolic<'a> {
+    connections: &'a HashMap<String, Operation>,
+    free: HashSet<&'a str>,
+    done: HashMap<String, Rc<Expr>>,
+    /// Wires being expanded, to report a loop instead of recursing forever.
+    pending: Vec<String>,
+}
+
+impl<'a> Symbolic<'a> {
+    fn operand(&mut self, operand: &NameOrNumber, used_by: &str) -> Result<Rc<Expr>, CircuitError> {
+        match operand {
+            NameOrNumber::Number(value) => Ok(Rc::new(Expr::Constant(*value))),
+            NameOrNumber::Name(name) => self.wire(name, used_by),
+        }
+    }
+
+    fn wire(&mut self, name: &str, used_by: &str) -> Result<Rc<Expr>, CircuitError> {
+        if let Some(expr) = self.done.get(name) {
+            return Ok(expr.clone());
+        }
+        if self.free.contains(name) {
+            return Ok(Rc::new(Expr::Input(name.to_string())));
+        }
+        if let Some(start) = self.pending.iter().position(|wire| wire == name) {
+            let mut cycle = self.pending[start..].to_vec();
+            cycle.push(name.to_string());
+            return Err(CircuitError::Cycle(cycle));
+        }
+        let connections = self.connections;
+        let operation = connections
+            .get(name)
+            .ok_or_else(|| CircuitError::UndefinedWire {
+                wire: name.to_string(),
+                used_by: used_by.to_string(),
+            })?;
+
+        self.pending.push(name.to_string());
+        let expr = match operation {
+            Operation::UnaryOperation((UnaryOp::EVAL, operand)) => self.operand(operand, name)?,
+            Operation::UnaryOperation((UnaryOp::NOT, operand)) => {
+                Expr::not(self.operand(operand, name)?)
+            }
+            Operation::BinaryOperation((op, first, second)) => {
+                let first = self.operand(first, name)?;
+                let second = self.operand(second, name)?;
+                Expr::binary(*op, first, second)
+            }
+        };
+        self.pending.pop();
+        self.done.insert(name.to_string(), expr.clone());
+        Ok(expr)
+    }
+}
+
+/// Build the simplified expression for `wire`, treating each wire in `free` as an unknown input
+/// instead of following its connection.
+pub fn symbolic(
+    connections: &HashMap<String, Operation>,
+    wire: &str,
+    free: &[&str],
+) -> Result<Rc<Expr>, CircuitError> {
+    let mut symbolic = Symbolic {
+        connections,
+        free: free.iter().copied().collect(),
+        done: HashMap::new(),
+        pending: Vec::new(),
+    };
+    symbolic.wire(wire, "the caller")
+}
+
+/// The wires set straight from a constant whose value can still change the signal on `wire`.
+pub fn influencing_inputs(
+    connections: &HashMap<String, Operation>,
+    wire: &str,
+) -> Result<BTreeSet<String>, CircuitError> {
+    let free: Vec<&str> = connections
+        .iter()
+        .filter(|(_, operation)| {
+            matches!(
+                operation,
+                Operation::UnaryOperation((UnaryOp::EVAL, NameOrNumber::Number(_)))
+            )
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+    Ok(symbolic(connections, wire, &free)?.inputs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connections(input: &str) -> HashMap<String, Operation> {
+        let mut connections = HashMap::new();
+        load_wire_connections(input, &mut connections).unwrap();
+        connections
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let connections = connections("123 -> x\nx LSHIFT 2 -> f\nNOT f -> a");
+        assert_eq!(
+            to_dot(&connections, None),
+            r#"digraph {
+    0 [ label = "a" shape = ellipse ]
+    1 [ label = "f" shape = ellipse ]
+    2 [ label = "x" shape = ellipse ]
+    3 [ label = "NOT" shape = box ]
+    4 [ label = "LSHIFT" shape = box ]
+    5 [ label = "2" shape = plaintext ]
+    6 [ label = "123" shape = plaintext ]
+    3 -> 0 [ ]
+    1 -> 3 [ ]
+    4 -> 1 [ ]
+    2 -> 4 [ ]
+    5 -> 4 [ ]
+    6 -> 2 [ ]
+}
+"#
+        );
+
+        let state: HashMap<String, u16> = vec![("x".to_string(), 123)].into_iter().collect();
+        let dot = to_dot(&connections, Some(&state));
+        assert!(dot.contains(r#"2 [ label = "x = 123" shape = ellipse ]"#));
+        assert!(dot.contains(r#"1 [ label = "f" shape = ellipse ]"#));
+    }
+
+    #[test]
+    fn test_symbolic() {
+        let connections = connections(
+            "123 -> x\nb AND 65535 -> c\nNOT c -> d\nNOT d -> e\ne LSHIFT 0 -> f\n\
+             x RSHIFT 2 -> g\nf OR g -> a\nx AND 0 -> h\nh OR b -> i\n5 -> b",
+        );
+
+        // Everything but b folds away or cancels out.
+        let a = symbolic(&connections, "a", &["b"]).unwrap();
+        assert_eq!(a.to_string(), "b OR 30");
+        assert_eq!(a.inputs(), vec!["b".to_string()].into_iter().collect());
+
+        let a = symbolic(&connections, "a", &[]).unwrap();
+        assert_eq!(*a, Expr::Constant(31));
+
+        let d = symbolic(&connections, "d", &["b", "x"]).unwrap();
+        assert_eq!(d.to_string(), "NOT b");
+        let both = symbolic(&connections, "i", &["b", "x"]).unwrap();
+        assert_eq!(both.to_string(), "b");
+
+        let inputs = influencing_inputs(&connections, "a").unwrap();
+        assert_eq!(
+            inputs,
+            vec!["b".to_string(), "x".to_string()].into_iter().collect()
+        );
+        let inputs = influencing_inputs(&connections, "i").unwrap();
+        assert_eq!(inputs, vec!["b".to_string()].into_iter().collect());
+    }
+
+    #[test]
+    fn test_symbolic_errors() {
+        let connections = connections("b -> a\nNOT c -> b\nb AND 1 -> c");
+        assert_eq!(
+            symbolic(&connections, "a", &[]).unwrap_err(),
+            CircuitError::Cycle(vec!["b".to_string(), "c".to_string(), "b".to_string()])
+        );
+        assert_eq!(
+            symbolic(&connections, "a", &["c"]).unwrap().to_string(),
+            "NOT c"
+        );
+        assert!(symbolic(&connections, "z", &[]).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use advent_support::Solution;
+use anyhow::Result;
+use itertools::Itertools;
+
+fn is_valid_triangle(sides: &[i64]) -> bool {
+    (sides[0] < sides[1] + sides[2])
+        && (sides[1] < sides[0] + sides[2])
+        && (sides[2] < sides[0] + sides[1])
+}
+
+fn count_valid_triangles(maybe_triangles: &[Vec<i64>]) -> usize {
+    maybe_triangles
+        .iter()
+        .filter(|x| is_valid_triangle(x))
+        .count()
+}
+
+fn parse_triangles(data: &str) -> Result<Vec<Vec<i64>>> {
+    data.lines()
+        .map(|line| {
+            Ok(line
+                .trim()
+                .split_ascii_whitespace()
+                .map(|x| x.parse())
+                .collect::<Result<Vec<i64>, _>>()?)
+        })
+        .collect()
+}
+
+// Transpose rows -> columns, then collect in groups of 3.
+fn triangles_by_column(triangles: &[Vec<i64>]) -> Vec<Vec<i64>> {
+    (0..3)
+        .flat_map(|col| {
+            (0..triangles.len())
+                .map(|row| triangles[row][col])
+                .chunks(3)
+                .into_iter()
+                .map(|x| x.into_iter().collect::<Vec<i64>>())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let triangles = parse_triangles(input)?;
+        Ok(count_valid_triangles(&triangles).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let triangles = parse_triangles(input)?;
+        Ok(count_valid_triangles(&triangles_by_column(&triangles)).to_string())
+    }
+}
@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use advent_support::Solution;
+use anyhow::Result;
+use itertools::Itertools;
+
+fn repetition_code(
+    input: &[Vec<char>],
+    sorter: fn(a: &(usize, &char), b: &(usize, &char)) -> Ordering,
+) -> String {
+    input
+        .iter()
+        .map(|row| {
+            row.iter()
+                .sorted()
+                .group_by(|x| *x)
+                .into_iter()
+                .map(|(key, group)| (group.count(), key))
+                .sorted_by(sorter)
+                .map(|x| x.1)
+                .next()
+                .unwrap()
+        })
+        .collect()
+}
+
+fn most_common(a: &(usize, &char), b: &(usize, &char)) -> Ordering {
+    match Ord::cmp(&b.0, &a.0) {
+        Ordering::Equal => Ord::cmp(&a.1, &b.1),
+        order => order,
+    }
+}
+
+fn least_common(a: &(usize, &char), b: &(usize, &char)) -> Ordering {
+    match Ord::cmp(&a.0, &b.0) {
+        Ordering::Equal => Ord::cmp(&a.1, &b.1),
+        order => order,
+    }
+}
+
+fn transposed_method(data: &str) -> (String, String) {
+    let input: Vec<Vec<char>> = data.lines().map(|line| line.chars().collect()).collect();
+
+    let column_count = input[0].len();
+    let row_count = input.len();
+
+    let transposed: Vec<Vec<char>> = (0..column_count)
+        .map(|col| {
+            (0..row_count)
+                .map(|row| input[row][col])
+                .collect::<Vec<char>>()
+        })
+        .collect();
+
+    let round1 = repetition_code(&transposed, most_common);
+    let round2 = repetition_code(&transposed, least_common);
+    (round1, round2)
+}
+
+fn counter_method(data: &str) -> (String, String) {
+    let mut counters = Vec::new();
+    for line in data.lines() {
+        let delta = line.len() - counters.len();
+        for _ in 0..delta {
+            counters.push(HashMap::new());
+        }
+        for (idx, c) in line.chars().enumerate() {
+            counters[idx]
+                .entry(c)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        }
+    }
+    let round1: String = counters
+        .iter()
+        .map(|counter| {
+            counter
+                .iter()
+                .sorted_by(|a, b| most_common(&(*a.1, a.0), &(*b.1, b.0)))
+                .map(|x| *x.0)
+                .next()
+                .unwrap()
+        })
+        .collect();
+    let round2: String = counters
+        .iter()
+        .map(|counter| {
+            counter
+                .iter()
+                .sorted_by(|a, b| least_common(&(*a.1, a.0), &(*b.1, b.0)))
+                .map(|x| *x.0)
+                .next()
+                .unwrap()
+        })
+        .collect();
+    (round1, round2)
+}
+
+fn error_corrected(input: &str) -> (String, String) {
+    let messages = transposed_method(input);
+    debug_assert_eq!(messages, counter_method(input));
+    messages
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(error_corrected(input).0)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(error_corrected(input).1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "eedadn
+drvtee
+eandsr
+raavrd
+atevrs
+tsrnev
+sdttsa
+rasrtv
+nssdts
+ntnada
+svetve
+tesnvt
+vntsnd
+vrdear
+dvrsen
+enarar
+";
+
+    #[test]
+    fn test_methods_agree() {
+        let expected = ("easter".to_string(), "advent".to_string());
+        assert_eq!(transposed_method(EXAMPLE), expected);
+        assert_eq!(counter_method(EXAMPLE), expected);
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use advent_support::Solution;
+use anyhow::Result;
+use itertools::Itertools;
+use nom::character::complete::{alpha1, char};
+use nom::sequence::delimited;
+use nom::IResult;
+
+fn supernet(input: &str) -> IResult<&str, &str> {
+    alpha1(input)
+}
+
+fn hypernet(input: &str) -> IResult<&str, &str> {
+    delimited(char('['), supernet, char(']'))(input)
+}
+
+fn parse_ip(input: &str) -> IResult<&str, (Vec<&str>, Vec<&str>)> {
+    let mut supernets = Vec::new();
+    let mut hypernets = Vec::new();
+
+    let mut input = input;
+    loop {
+        let (next_input, next_net) = supernet(input)?;
+        supernets.push(next_net);
+        if next_input.is_empty() {
+            break;
+        }
+        input = next_input;
+        let (next_input, next_hypernet) = hypernet(input)?;
+        hypernets.push(next_hypernet);
+        input = next_input;
+    }
+
+    Ok((input, (supernets, hypernets)))
+}
+
+fn parse(input: &str) -> (Vec<&str>, Vec<&str>) {
+    match parse_ip(input) {
+        Ok((_, result)) => result,
+        Err(msg) => panic!("{}", msg),
+    }
+}
+
+fn does_support_ssl(supernets: &[&str], hypernets: &[&str]) -> bool {
+    let abas: HashSet<(_, _, _)> = supernets
+        .iter()
+        .flat_map(|x| {
+            (*x).chars()
+                .tuple_windows::<(_, _, _)>()
+                // ABA
+                .filter(|x| x.0 == x.2 && x.0 != x.1)
+        })
+        .collect();
+    // Find the BAB, then invert it to be an ABA.
+    let babs: HashSet<(_, _, _)> = hypernets
+        .iter()
+        .flat_map(|x| {
+            (*x).chars()
+                .tuple_windows::<(_, _, _)>()
+                .filter(|x| x.0 == x.2 && x.0 != x.1)
+        })
+        .map(|x| (x.1, x.0, x.1))
+        .collect();
+    abas.intersection(&babs).next().is_some()
+}
+
+fn does_support_tls(supernets: &[&str], hypernets: &[&str]) -> bool {
+    !hypernets.iter().any(|x| has_abba(x)) && supernets.iter().any(|x| has_abba(x))
+}
+
+fn has_abba(input: &str) -> bool {
+    input.chars().tuple_windows::<(_, _, _, _)>().any(|window| {
+        let (a, b, c, d) = window;
+        a == d && b == c && a != b
+    })
+}
+
+fn count_supporting(data: &str, supports: fn(&[&str], &[&str]) -> bool) -> usize {
+    data.lines()
+        .map(parse)
+        .filter(|(supernets, hypernets)| supports(supernets, hypernets))
+        .count()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(count_supporting(input, does_support_tls).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(count_supporting(input, does_support_ssl).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tls() {
+        let cases = [
+            ("abba[mnop]qrst", true),
+            ("abcd[bddb]xyyx", false),
+            ("aaaa[qwer]tyui", false),
+            ("ioxxoj[asdfgh]zxcvbn", true),
+        ];
+
+        for (ip, expected) in cases {
+            let (supernets, hypernets) = parse(ip);
+            assert_eq!(does_support_tls(&supernets, &hypernets), expected, "{ip:?}");
+        }
+    }
+
+    #[test]
+    fn test_ssl() {
+        let cases = [
+            ("aba[bab]xyz", true),
+            ("xyx[xyx]xyx", false),
+            ("aaa[kek]eke", true),
+            ("zazbz[bzb]cdb", true),
+        ];
+
+        for (ip, expected) in cases {
+            let (supernets, hypernets) = parse(ip);
+            assert_eq!(does_support_ssl(&supernets, &hypernets), expected, "{ip:?}");
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use advent_support::read_input_string;
+use anyhow::Result;
+
+use aoc2016_day8::dead_lines;
+
+/// Reads the program on stdin and lists the lines that could be removed without changing the
+/// final screen.
+fn main() -> Result<()> {
+    for line in dead_lines(&read_input_string()?)? {
+        println!("{}", line);
+    }
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use std::env;
+use std::path::Path;
+
+use advent_support::read_input_string;
+use anyhow::{anyhow, Result};
+
+use aoc2016_day8::render;
+
+/// Reads the program on stdin and draws the screen to the .png, .ppm or .gif file named.
+fn main() -> Result<()> {
+    let path = env::args()
+        .nth(1)
+        .ok_or_else(|| anyhow!("usage: render <output.png|.ppm|.gif> < input"))?;
+    render(&read_input_string()?, Path::new(&path))
+}
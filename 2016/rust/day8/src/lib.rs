@@ -0,0 +1,228 @@
+use std::io::Write;
+use std::path::Path;
+
+use advent_support::{read_letters, Animation, ParseError, Solution, TerminalAnimation};
+use anyhow::Result;
+use regex::{Captures, Regex};
+
+pub mod reverse;
+pub mod screen;
+use screen::{Instruction, Screen};
+
+fn run_program(data: &str) -> Result<Screen> {
+    run_program_on(Screen::new(50, 6), data)
+}
+
+fn run_program_on(screen: Screen, data: &str) -> Result<Screen> {
+    run_program_observed(screen, data, |_| Ok(()))
+}
+
+/// Read a program for a `width` by `height` screen, one instruction per line.
+pub fn parse_program(data: &str, width: usize, height: usize) -> Result<Vec<Instruction>> {
+    let rect_re = Regex::new(r"^rect (\d+)x(\d+)$").unwrap();
+    let rotate_re = Regex::new(r"^rotate (column|row) (?:x|y)=(\d+) by (\d+)$").unwrap();
+
+    let mut program = Vec::new();
+    for (number, op) in (1..).zip(data.lines()) {
+        // Numbers are all matched by `\d+`, so only their range needs checking.
+        let value =
+            |captures: &Captures, index: usize, expected: &str, fits: &dyn Fn(usize) -> bool| {
+                let capture = captures.get(index).unwrap();
+                match capture.as_str().parse() {
+                    Ok(value) if fits(value) => Ok(value),
+                    _ => Err(ParseError::in_input(
+                        data,
+                        number,
+                        capture.start() + 1,
+                        expected,
+                    )),
+                }
+            };
+
+        let instruction = if let Some(captures) = rect_re.captures(op) {
+            Instruction::Rect {
+                width: value(&captures, 1, "a width that fits the screen", &|w| {
+                    w <= width
+                })?,
+                height: value(&captures, 2, "a height that fits the screen", &|h| {
+                    h <= height
+                })?,
+            }
+        } else if let Some(captures) = rotate_re.captures(op) {
+            let count = value(&captures, 3, "a count", &|_| true)?;
+
+            match &captures[1] {
+                "row" => Instruction::RotateRow {
+                    y: value(&captures, 2, "a row on the screen", &|y| y < height)?,
+                    count,
+                },
+                "column" => Instruction::RotateColumn {
+                    x: value(&captures, 2, "a column on the screen", &|x| x < width)?,
+                    count,
+                },
+                _ => unreachable!(),
+            }
+        } else {
+            return Err(ParseError::in_input(
+                data,
+                number,
+                1,
+                "`rect AxB`, `rotate row y=A by B` or `rotate column x=A by B`",
+            )
+            .into());
+        };
+        program.push(instruction);
+    }
+
+    Ok(program)
+}
+
+/// Run the program, handing the screen to `observe` after every instruction.
+fn run_program_observed(
+    mut screen: Screen,
+    data: &str,
+    mut observe: impl FnMut(&Screen) -> Result<()>,
+) -> Result<Screen> {
+    for instruction in parse_program(data, screen.width(), screen.height())? {
+        screen.apply(&instruction);
+        observe(&screen)?;
+    }
+
+    Ok(screen)
+}
+
+/// Line numbers of the instructions that make no difference to the final screen.
+pub fn dead_lines(data: &str) -> Result<Vec<usize>> {
+    let program = parse_program(data, 50, 6)?;
+    let mut screen = Screen::new(50, 6);
+    for instruction in &program {
+        screen.apply(instruction);
+    }
+    let dead = reverse::dead_instructions(&program, screen.pixels())?;
+    Ok(dead.into_iter().map(|index| index + 1).collect())
+}
+
+/// Redraw the screen in the terminal after every instruction.
+pub fn animate<W: Write>(data: &str, animation: &mut TerminalAnimation<W>) -> Result<()> {
+    let screen = Screen::new(50, 6);
+    animation.frame(&screen.to_string())?;
+    run_program_observed(screen, data, |screen| animation.frame(&screen.to_string()))?;
+    Ok(())
+}
+
+/// Draw the final screen as a PNG or PPM, or every step of the program as a GIF, depending on
+/// the extension of `path`.
+pub fn render(data: &str, path: &Path) -> Result<()> {
+    if path.extension().is_some_and(|extension| extension == "gif") {
+        let screen = Screen::new(50, 6);
+        let first = screen.image();
+        let mut animation = Animation::create(path, first.width(), first.height(), 10)?;
+        animation.add(&first)?;
+        run_program_observed(screen, data, |screen| animation.add(&screen.image()))?;
+        Ok(())
+    } else {
+        run_program(data)?.image().save(path)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(run_program(input)?.count().to_string())
+    }
+
+    /// The letters on the screen, or the screen itself when they cannot all be read.
+    fn part2(&self, input: &str) -> Result<String> {
+        let screen = run_program(input)?;
+        let letters = read_letters(screen.pixels(), |lit| *lit);
+        if letters.contains('?') {
+            Ok(screen.to_string())
+        } else {
+            Ok(letters)
+        }
+    }
+}
+
+#[test]
+fn tests() {
+    let program = "rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1
+";
+    let screen = run_program_on(Screen::new(7, 3), program).unwrap();
+    assert_eq!(screen.count(), 6);
+    assert_eq!(
+        screen.to_string(),
+        ".=======.\n| #  # #|\n|# #    |\n| #     |\n.=======."
+    );
+}
+
+#[test]
+fn test_observed() {
+    let program = "rect 3x2\nrotate row y=0 by 4\n";
+    let mut counts = Vec::new();
+    run_program_observed(Screen::new(7, 3), program, |screen| {
+        counts.push(screen.count());
+        Ok(())
+    })
+    .unwrap();
+    assert_eq!(counts, vec![6, 6]);
+
+    let image = run_program_on(Screen::new(7, 3), program).unwrap().image();
+    assert_eq!((image.width(), image.height()), (70, 30));
+    assert_eq!(image.pixels()[0], 0);
+    assert_eq!(image.pixels()[40], 255);
+}
+
+#[test]
+fn test_letters() {
+    let program = "rect 4x1
+rotate column x=0 by 5
+rotate column x=1 by 5
+rotate column x=2 by 5
+rotate column x=3 by 5
+rect 1x5
+";
+    assert_eq!(Day8.part2(program).unwrap(), "L");
+
+    // Not a letter, so the answer is the screen itself.
+    let answer = Day8.part2("rect 3x3\n").unwrap();
+    assert!(answer.starts_with(".=====") && answer.contains("|###  "));
+}
+
+#[test]
+fn test_animate() {
+    let mut animation = TerminalAnimation::new(Vec::new(), std::time::Duration::ZERO);
+    animate("rect 3x2\nrotate row y=0 by 4\n", &mut animation).unwrap();
+    let out = String::from_utf8(animation.into_inner()).unwrap();
+    // The blank screen and one frame per instruction.
+    assert_eq!(out.matches("\x1b[H").count(), 3);
+    let last = out.rsplit("\x1b[H").next().unwrap();
+    assert!(last.contains(&format!("\n|    ###{}|\x1b[K\n|###", " ".repeat(43))));
+}
+
+#[test]
+fn test_dead_lines() {
+    // The second rect covers the whole row the first one drew on.
+    let program = "rect 2x1\nrotate row y=0 by 7\nrect 50x1\nrotate column x=3 by 1\n";
+    assert_eq!(dead_lines(program).unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_errors() {
+    let error = run_program_on(Screen::new(7, 3), "rect 3x2\nrotate row y=3 by 1\n").unwrap_err();
+    let error = error.downcast::<ParseError>().unwrap();
+    assert_eq!((error.line(), error.column()), (2, 14));
+    assert_eq!(error.expected(), "a row on the screen");
+
+    // Nothing fits on a screen with no rows.
+    let error = parse_program("rotate row y=0 by 1\n", 7, 0).unwrap_err();
+    let error = error.downcast::<ParseError>().unwrap();
+    assert_eq!(error.expected(), "a row on the screen");
+
+    let error = run_program_on(Screen::new(7, 3), "rect 3x2\nswap x=1\n").unwrap_err();
+    let error = error.downcast::<ParseError>().unwrap();
+    assert_eq!((error.line(), error.column()), (2, 1));
+}
@@ -0,0 +1,220 @@
+use std::error::Error;
+use std::fmt;
+
+use advent_support::Grid;
+
+use crate::screen::Instruction;
+
+/// A pixel of the target image, as `(x, y)`.
+pub type Pixel = (usize, usize);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReverseError {
+    /// The rect at `instruction` lights a pixel that is dark in the target.
+    LitByRect { instruction: usize, pixel: Pixel },
+    /// A pixel lit in the target that no rect ever reaches, so it would still be dark.
+    NeverLit(Pixel),
+}
+
+impl fmt::Display for ReverseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReverseError::LitByRect {
+                instruction,
+                pixel: (x, y),
+            } => write!(
+                f,
+                "instruction {} lights ({}, {}), which is dark in the target",
+                instruction + 1,
+                x,
+                y
+            ),
+            ReverseError::NeverLit((x, y)) => {
+                write!(
+                    f,
+                    "({}, {}) is lit in the target but no rect lights it",
+                    x, y
+                )
+            }
+        }
+    }
+}
+
+impl Error for ReverseError {}
+
+/// Run `program` backwards from the `target` image. Rotations are undone, and each rect marks
+/// the pixels it covers as ones it must have set, so nothing earlier decides them.
+///
+/// The result has, for every instruction, the target pixels whose value passes through the
+/// cells it touches: the pixels a rect sets that no later rect sets again, or the pixels a
+/// rotation moves. Fails if the program cannot draw `target` on a blank screen.
+pub fn run_backwards(
+    program: &[Instruction],
+    target: &Grid<bool>,
+) -> Result<Vec<Vec<Pixel>>, ReverseError> {
+    let (width, height) = (target.width(), target.height());
+    // Which target pixel each cell ends up as, or `None` once a later rect overwrites it.
+    let mut origin: Grid<Option<Pixel>> = Grid::new(width, height, None);
+    for y in 0..height {
+        for x in 0..width {
+            origin[(x, y)] = Some((x, y));
+        }
+    }
+
+    let mut constrained = vec![Vec::new(); program.len()];
+    for (index, instruction) in program.iter().enumerate().rev() {
+        let pixels = &mut constrained[index];
+        match *instruction {
+            Instruction::Rect {
+                width: fill_width,
+                height: fill_height,
+            } => {
+                for y in 0..fill_height {
+                    for x in 0..fill_width {
+                        if let Some(pixel) = origin[(x, y)].take() {
+                            if !target[pixel] {
+                                return Err(ReverseError::LitByRect {
+                                    instruction: index,
+                                    pixel,
+                                });
+                            }
+                            pixels.push(pixel);
+                        }
+                    }
+                }
+            }
+            Instruction::RotateRow { y, count } => {
+                pixels.extend(origin.row(y).iter().flatten());
+                origin.rotate_row(y, width - count % width);
+            }
+            Instruction::RotateColumn { x, count } => {
+                pixels.extend(origin.column(x).flatten());
+                origin.rotate_column(x, height - count % height);
+            }
+        }
+        pixels.sort_unstable();
+    }
+
+    // Whatever is left comes from the blank screen the program starts on.
+    if let Some(pixel) = origin.iter().flatten().find(|pixel| target[**pixel]) {
+        return Err(ReverseError::NeverLit(*pixel));
+    }
+    Ok(constrained)
+}
+
+/// Instructions, by index, that no pixel of the target passes through. Removing them does not
+/// change the final image.
+pub fn dead_instructions(
+    program: &[Instruction],
+    target: &Grid<bool>,
+) -> Result<Vec<usize>, ReverseError> {
+    let constrained = run_backwards(program, target)?;
+    Ok((0..program.len())
+        .filter(|index| constrained[*index].is_empty())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use super::*;
+    use crate::screen::tests::screen_after;
+    use crate::screen::Screen;
+
+    fn final_image(screen: Screen, program: &[Instruction]) -> Grid<bool> {
+        let mut screen = screen;
+        for instruction in program {
+            screen.apply(instruction);
+        }
+        screen.pixels().clone()
+    }
+
+    #[test]
+    fn test_run_backwards() {
+        let program = [
+            Instruction::Rect {
+                width: 3,
+                height: 2,
+            },
+            Instruction::RotateColumn { x: 1, count: 1 },
+            Instruction::RotateRow { y: 0, count: 4 },
+            Instruction::RotateColumn { x: 1, count: 1 },
+        ];
+        let target = final_image(Screen::new(7, 3), &program);
+        let constrained = run_backwards(&program, &target).unwrap();
+
+        // Exactly the lit pixels come from the one rect.
+        let mut lit: Vec<Pixel> = target
+            .positions()
+            .filter(|(_, lit)| **lit)
+            .map(|(pixel, _)| pixel)
+            .collect();
+        lit.sort_unstable();
+        assert_eq!(constrained[0], lit);
+        assert_eq!(constrained[3], vec![(1, 0), (1, 1), (1, 2)]);
+        assert_eq!(constrained[2].len(), 7);
+        assert_eq!(dead_instructions(&program, &target).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_dead_instructions() {
+        let program = [
+            Instruction::Rect {
+                width: 2,
+                height: 2,
+            },
+            Instruction::Rect {
+                width: 3,
+                height: 1,
+            },
+            Instruction::RotateRow { y: 0, count: 1 },
+            Instruction::Rect {
+                width: 3,
+                height: 3,
+            },
+        ];
+        let target = final_image(Screen::new(5, 3), &program);
+        // The last rect covers everything the first two drew, moved or not.
+        assert_eq!(run_backwards(&program, &target).unwrap()[1], vec![(3, 0)]);
+        assert_eq!(dead_instructions(&program, &target).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn test_unreachable_target() {
+        let program = [Instruction::Rect {
+            width: 2,
+            height: 1,
+        }];
+        let mut target = final_image(Screen::new(4, 2), &program);
+        target[(3, 1)] = true;
+        assert_eq!(
+            run_backwards(&program, &target),
+            Err(ReverseError::NeverLit((3, 1)))
+        );
+
+        target[(3, 1)] = false;
+        target[(1, 0)] = false;
+        assert_eq!(
+            run_backwards(&program, &target),
+            Err(ReverseError::LitByRect {
+                instruction: 0,
+                pixel: (1, 0)
+            })
+        );
+    }
+
+    quickcheck! {
+        fn prop_dead_instructions_change_nothing(program: Vec<Instruction>) -> bool {
+            let target = screen_after(&program);
+            let dead = dead_instructions(&program, target.pixels()).unwrap();
+            let live: Vec<Instruction> = program
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !dead.contains(index))
+                .map(|(_, instruction)| *instruction)
+                .collect();
+            screen_after(&live) == target
+        }
+    }
+}
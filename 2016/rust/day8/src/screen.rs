@@ -0,0 +1,308 @@
+use std::fmt::{self, Display};
+
+use advent_support::{Grid, Image};
+
+/// One line of a screen program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Rect { width: usize, height: usize },
+    RotateRow { y: usize, count: usize },
+    RotateColumn { x: usize, count: usize },
+}
+
+/// The little screen on the door, every pixel either lit or dark.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Screen {
+    pixels: Grid<bool>,
+}
+
+impl Screen {
+    pub fn new(width: usize, height: usize) -> Self {
+        Screen {
+            pixels: Grid::new(width, height, false),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.pixels.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.height()
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    pub fn count(&self) -> usize {
+        self.pixels.iter().filter(|x| **x).count()
+    }
+
+    /// Light every pixel in the `width` by `height` rectangle at the top left.
+    pub fn rect(&mut self, width: usize, height: usize) {
+        for y in 0..height {
+            for x in 0..width {
+                self.pixels[(x, y)] = true;
+            }
+        }
+    }
+
+    pub fn apply(&mut self, instruction: &Instruction) {
+        match *instruction {
+            Instruction::Rect { width, height } => self.rect(width, height),
+            Instruction::RotateRow { y, count } => self.rotate_row(y, count),
+            Instruction::RotateColumn { x, count } => self.rotate_column(x, count),
+        }
+    }
+
+    pub fn rotate_row(&mut self, y: usize, count: usize) {
+        self.shift_rect(0, y, self.width(), 1, count, 0);
+    }
+
+    pub fn rotate_column(&mut self, x: usize, count: usize) {
+        self.shift_rect(x, 0, 1, self.height(), 0, count);
+    }
+
+    /// Move every pixel of the `width` by `height` rectangle at `(left, top)` right by `dx`
+    /// and down by `dy`, with pixels pushed off one edge coming back in on the other side of the
+    /// rectangle. Nothing outside the rectangle changes.
+    pub fn shift_rect(
+        &mut self,
+        left: usize,
+        top: usize,
+        width: usize,
+        height: usize,
+        dx: usize,
+        dy: usize,
+    ) {
+        assert!(
+            left + width <= self.width() && top + height <= self.height(),
+            "{}x{} at ({}, {}) does not fit a {}x{} screen",
+            width,
+            height,
+            left,
+            top,
+            self.width(),
+            self.height()
+        );
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let before = self.pixels.clone();
+        for y in 0..height {
+            for x in 0..width {
+                let to = (left + (x + dx) % width, top + (y + dy) % height);
+                self.pixels[to] = before[(left + x, top + y)];
+            }
+        }
+    }
+
+    pub fn image(&self) -> Image {
+        Image::from_grid(&self.pixels, 10, |lit| if *lit { 255 } else { 0 })
+    }
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let border = "=".repeat(self.pixels.width());
+        writeln!(f, ".{border}.")?;
+        for row in self.pixels.rows() {
+            write!(f, "|")?;
+            for &lit in row {
+                let symbol = match lit {
+                    true => '#',
+                    false => ' ',
+                };
+                write!(f, "{symbol}")?;
+            }
+            writeln!(f, "|")?;
+        }
+        write!(f, ".{border}")?;
+        write!(f, ".")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use quickcheck::{quickcheck, Arbitrary, Gen};
+
+    use super::*;
+
+    const WIDTH: usize = 50;
+    const HEIGHT: usize = 6;
+
+    /// The first version: free functions over a row-major buffer, where rotations read from a
+    /// copy saved after the previous instruction.
+    struct DoubleBuffer {
+        screen: Vec<bool>,
+        source: Vec<bool>,
+    }
+
+    impl DoubleBuffer {
+        fn index(x: usize, y: usize) -> usize {
+            x + (y * WIDTH)
+        }
+
+        fn apply(&mut self, instruction: Instruction) {
+            match instruction {
+                Instruction::Rect {
+                    width: fill_width,
+                    height: fill_height,
+                } => {
+                    for y in 0..fill_height {
+                        for x in 0..fill_width {
+                            self.screen[Self::index(x, y)] = true;
+                        }
+                    }
+                }
+                Instruction::RotateRow { y, count } => {
+                    for x in 0..WIDTH {
+                        let new_x = (x + count) % WIDTH;
+                        self.screen[Self::index(new_x, y)] = self.source[Self::index(x, y)];
+                    }
+                }
+                Instruction::RotateColumn { x, count } => {
+                    for y in 0..HEIGHT {
+                        let new_y = (y + count) % HEIGHT;
+                        self.screen[Self::index(x, new_y)] = self.source[Self::index(x, y)];
+                    }
+                }
+            }
+            self.source.copy_from_slice(&self.screen);
+        }
+
+        fn lit(&self, x: usize, y: usize) -> bool {
+            self.screen[Self::index(x, y)]
+        }
+    }
+
+    /// The second version: one object with a column-major buffer and a saved copy of it.
+    struct ColumnMajor {
+        backend1: Vec<bool>,
+        backend2: Vec<bool>,
+    }
+
+    impl ColumnMajor {
+        fn index(x: usize, y: usize) -> usize {
+            (x * HEIGHT) + y
+        }
+
+        fn apply(&mut self, instruction: Instruction) {
+            match instruction {
+                Instruction::Rect { width, height } => {
+                    for x in 0..width {
+                        for y in 0..height {
+                            self.backend1[Self::index(x, y)] = true;
+                        }
+                    }
+                }
+                Instruction::RotateRow { y, count } => {
+                    for x in 0..WIDTH {
+                        let new_x = (x + count) % WIDTH;
+                        self.backend1[Self::index(new_x, y)] = self.backend2[Self::index(x, y)];
+                    }
+                }
+                Instruction::RotateColumn { x, count } => {
+                    for y in 0..HEIGHT {
+                        let new_y = (y + count) % HEIGHT;
+                        self.backend1[Self::index(x, new_y)] = self.backend2[Self::index(x, y)];
+                    }
+                }
+            }
+            self.backend2.copy_from_slice(&self.backend1);
+        }
+
+        fn lit(&self, x: usize, y: usize) -> bool {
+            self.backend1[Self::index(x, y)]
+        }
+    }
+
+    impl Arbitrary for Instruction {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let below = |g: &mut Gen, limit: usize| usize::arbitrary(g) % limit;
+            match below(g, 3) {
+                0 => Instruction::Rect {
+                    width: below(g, WIDTH + 1),
+                    height: below(g, HEIGHT + 1),
+                },
+                1 => Instruction::RotateRow {
+                    y: below(g, HEIGHT),
+                    count: below(g, 2 * WIDTH),
+                },
+                _ => Instruction::RotateColumn {
+                    x: below(g, WIDTH),
+                    count: below(g, 2 * HEIGHT),
+                },
+            }
+        }
+    }
+
+    pub(crate) fn screen_after(program: &[Instruction]) -> Screen {
+        let mut screen = Screen::new(WIDTH, HEIGHT);
+        for instruction in program {
+            screen.apply(instruction);
+        }
+        screen
+    }
+
+    #[test]
+    fn test_shift_rect() {
+        let mut screen = Screen::new(5, 3);
+        screen.rect(2, 2);
+        // Only the lit pixel at (1, 1) is inside the rectangle.
+        screen.shift_rect(1, 1, 3, 2, 1, 1);
+        assert_eq!(
+            screen.to_string(),
+            ".=====.\n|##   |\n|#    |\n|  #  |\n.=====."
+        );
+    }
+
+    quickcheck! {
+        fn prop_backends_agree(program: Vec<Instruction>) -> bool {
+            let mut double_buffer = DoubleBuffer {
+                screen: vec![false; WIDTH * HEIGHT],
+                source: vec![false; WIDTH * HEIGHT],
+            };
+            let mut column_major = ColumnMajor {
+                backend1: vec![false; WIDTH * HEIGHT],
+                backend2: vec![false; WIDTH * HEIGHT],
+            };
+            for instruction in &program {
+                double_buffer.apply(*instruction);
+                column_major.apply(*instruction);
+            }
+
+            let screen = screen_after(&program);
+            let agree = screen.pixels().positions().all(|((x, y), lit)| {
+                double_buffer.lit(x, y) == *lit && column_major.lit(x, y) == *lit
+            });
+            agree
+        }
+
+        fn prop_full_rotation_is_identity(program: Vec<Instruction>, index: usize) -> bool {
+            let before = screen_after(&program);
+            let mut screen = before.clone();
+            screen.rotate_row(index % HEIGHT, WIDTH);
+            screen.rotate_column(index % WIDTH, HEIGHT);
+            screen == before
+        }
+
+        fn prop_shift_rect_undoes(program: Vec<Instruction>, corner: (usize, usize), size: (usize, usize), shift: (usize, usize)) -> bool {
+            let (left, top) = (corner.0 % WIDTH, corner.1 % HEIGHT);
+            let width = size.0 % (WIDTH - left) + 1;
+            let height = size.1 % (HEIGHT - top) + 1;
+            let (dx, dy) = (shift.0 % width, shift.1 % height);
+
+            let before = screen_after(&program);
+            let mut screen = before.clone();
+            screen.shift_rect(left, top, width, height, dx, dy);
+            let moved = screen.count() == before.count();
+            screen.shift_rect(left, top, width, height, width - dx, height - dy);
+            moved && screen == before
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use advent_support::Solution;
+use anyhow::{anyhow, bail, Result};
+
+use crate::intcode::{parse, Intcode, State};
+
+/// Run one amplifier per phase setting, wired in a ring so each output becomes the next
+/// amplifier's input, starting with a signal of zero. Amplifiers that halt after a single signal
+/// make a plain chain; ones that keep going form a feedback loop. Either way the result is the
+/// last signal out of the final amplifier.
+pub fn run_amplifiers(program: &[i64], phases: &[i64]) -> Result<i64> {
+    let mut amplifiers: Vec<Intcode> = phases
+        .iter()
+        .map(|phase| {
+            let mut amplifier = Intcode::new(program);
+            amplifier.push_input(*phase);
+            amplifier
+        })
+        .collect();
+
+    let mut signal = 0;
+    let mut thruster = None;
+    loop {
+        for (index, amplifier) in amplifiers.iter_mut().enumerate() {
+            amplifier.push_input(signal);
+            match amplifier.resume()? {
+                State::Output(value) => signal = value,
+                State::Halted => {
+                    return thruster.ok_or_else(|| anyhow!("no signal reached the thrusters"))
+                }
+                State::NeedsInput => {
+                    bail!("amplifier {} needs more than one input per signal", index)
+                }
+            }
+        }
+        thruster = Some(signal);
+    }
+}
+
+fn permutations(items: &[i64]) -> Vec<Vec<i64>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+    (0..items.len())
+        .flat_map(|index| {
+            let mut rest = items.to_vec();
+            let first = rest.remove(index);
+            permutations(&rest).into_iter().map(move |mut permutation| {
+                permutation.insert(0, first);
+                permutation
+            })
+        })
+        .collect()
+}
+
+/// Try every ordering of `phases`, returning the best ordering and its thruster signal.
+pub fn best_phases(program: &[i64], phases: &[i64]) -> Result<(Vec<i64>, i64)> {
+    let mut best: Option<(Vec<i64>, i64)> = None;
+    for permutation in permutations(phases) {
+        let signal = run_amplifiers(program, &permutation)?;
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_signal)| signal > *best_signal)
+        {
+            best = Some((permutation, signal));
+        }
+    }
+    best.ok_or_else(|| anyhow!("no phase settings"))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let (_, signal) = best_phases(&parse(input)?, &[0, 1, 2, 3, 4])?;
+        Ok(signal.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let (_, signal) = best_phases(&parse(input)?, &[5, 6, 7, 8, 9])?;
+        Ok(signal.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(name: &str) -> Vec<i64> {
+        let path = format!("{}/../../7/{}", env!("CARGO_MANIFEST_DIR"), name);
+        parse(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_permutations() {
+        let all = permutations(&[1, 2, 3]);
+        assert_eq!(all.len(), 6);
+        assert!(all.contains(&vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn test_chain() {
+        let (phases, signal) = best_phases(&example("input.1"), &[0, 1, 2, 3, 4]).unwrap();
+        assert_eq!((phases, signal), (vec![4, 3, 2, 1, 0], 43210));
+        let (phases, signal) = best_phases(&example("input.2"), &[0, 1, 2, 3, 4]).unwrap();
+        assert_eq!((phases, signal), (vec![0, 1, 2, 3, 4], 54321));
+        let (phases, signal) = best_phases(&example("input.3"), &[0, 1, 2, 3, 4]).unwrap();
+        assert_eq!((phases, signal), (vec![1, 0, 4, 3, 2], 65210));
+    }
+
+    #[test]
+    fn test_feedback() {
+        let program = example("input.feedback.1");
+        assert_eq!(
+            run_amplifiers(&program, &[9, 8, 7, 6, 5]).unwrap(),
+            139629729
+        );
+        let (phases, signal) = best_phases(&program, &[5, 6, 7, 8, 9]).unwrap();
+        assert_eq!((phases, signal), (vec![9, 8, 7, 6, 5], 139629729));
+    }
+}
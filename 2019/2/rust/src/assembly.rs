@@ -0,0 +1,368 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use advent_support::ParseError;
+
+/// Mnemonic, parameter count and which parameter (if any) is written to, indexed by opcode.
+const INSTRUCTIONS: [(i64, &str, usize, Option<usize>); 10] = [
+    (1, "add", 3, Some(2)),
+    (2, "mul", 3, Some(2)),
+    (3, "in", 1, Some(0)),
+    (4, "out", 1, None),
+    (5, "jnz", 2, None),
+    (6, "jz", 2, None),
+    (7, "lt", 3, Some(2)),
+    (8, "eq", 3, Some(2)),
+    (9, "arb", 1, None),
+    (99, "hlt", 0, None),
+];
+
+#[derive(Debug)]
+enum Item {
+    Instruction {
+        mnemonic: &'static str,
+        parameters: Vec<(i64, i64)>,
+    },
+    Data(i64),
+}
+
+/// Decode the instruction at `address`, if the value there is one this VM could execute and all
+/// of its parameters fit in the program.
+fn decode(program: &[i64], address: usize) -> Option<Item> {
+    let value = program[address];
+    if value < 0 {
+        return None;
+    }
+    let &(_, mnemonic, count, written) = INSTRUCTIONS
+        .iter()
+        .find(|(opcode, ..)| *opcode == value % 100)?;
+    let raw = program.get(address + 1..address + 1 + count)?;
+
+    let mut modes = value / 100;
+    let mut parameters = Vec::with_capacity(count);
+    for (index, raw) in raw.iter().enumerate() {
+        let mode = modes % 10;
+        if mode > 2 || (mode == 1 && written == Some(index)) {
+            return None;
+        }
+        parameters.push((mode, *raw));
+        modes /= 10;
+    }
+    // Mode digits for parameters that do not exist would be lost on the way back.
+    (modes == 0).then_some(Item::Instruction {
+        mnemonic,
+        parameters,
+    })
+}
+
+fn decode_all(program: &[i64]) -> Vec<(usize, Item)> {
+    let mut items = Vec::new();
+    let mut address = 0;
+    while address < program.len() {
+        let item = decode(program, address).unwrap_or(Item::Data(program[address]));
+        let size = match &item {
+            Item::Instruction { parameters, .. } => parameters.len() + 1,
+            Item::Data(_) => 1,
+        };
+        items.push((address, item));
+        address += size;
+    }
+    items
+}
+
+fn label(address: i64) -> String {
+    format!("L{}", address)
+}
+
+/// List a program one instruction per line, each followed by a comment with its address.
+///
+/// Parameters are written `[12]` in position mode, `12` in immediate mode and `[rb+12]` in
+/// relative mode. Immediate jump targets that land on an instruction get a label. Anything that
+/// does not decode as an instruction is listed as `data`, so [`assemble`] always rebuilds the
+/// exact same program.
+pub fn disassemble(program: &[i64]) -> String {
+    let items = decode_all(program);
+    let starts: BTreeSet<i64> = items
+        .iter()
+        .filter(|(_, item)| matches!(item, Item::Instruction { .. }))
+        .map(|(address, _)| *address as i64)
+        .collect();
+    let targets: BTreeSet<i64> = items
+        .iter()
+        .filter_map(|(_, item)| match item {
+            Item::Instruction {
+                mnemonic: "jnz" | "jz",
+                parameters,
+            } if parameters[1].0 == 1 => Some(parameters[1].1),
+            _ => None,
+        })
+        .filter(|target| starts.contains(target))
+        .collect();
+
+    let mut listing = String::new();
+    for (address, item) in items {
+        if targets.contains(&(address as i64)) {
+            listing.push_str(&format!("{}:\n", label(address as i64)));
+        }
+        let text = match item {
+            Item::Instruction {
+                mnemonic,
+                parameters,
+            } => {
+                let operands: Vec<String> = parameters
+                    .iter()
+                    .enumerate()
+                    .map(|(index, &(mode, raw))| match mode {
+                        0 => format!("[{}]", raw),
+                        2 => format!("[rb{:+}]", raw),
+                        _ if index == 1
+                            && matches!(mnemonic, "jnz" | "jz")
+                            && targets.contains(&raw) =>
+                        {
+                            label(raw)
+                        }
+                        _ => raw.to_string(),
+                    })
+                    .collect();
+                format!("{:<4}{}", mnemonic, operands.join(", "))
+            }
+            Item::Data(value) => format!("data {}", value),
+        };
+        listing.push_str(&format!("    {:<36}; {}\n", text.trim_end(), address));
+    }
+    listing
+}
+
+enum Operand<'a> {
+    Value(i64, i64),
+    Label(&'a str),
+}
+
+fn operand(text: &str) -> Option<Operand<'_>> {
+    let text = text.trim();
+    if let Some(inner) = text.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+        let inner = inner.trim();
+        return match inner.strip_prefix("rb") {
+            Some("") => Some(Operand::Value(2, 0)),
+            Some(offset) => Some(Operand::Value(
+                2,
+                offset.trim().replace(' ', "").parse().ok()?,
+            )),
+            None => Some(Operand::Value(0, inner.parse().ok()?)),
+        };
+    }
+    if let Ok(value) = text.parse() {
+        return Some(Operand::Value(1, value));
+    }
+    let mut chars = text.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    valid.then_some(Operand::Label(text))
+}
+
+/// Turn a listing in the format produced by [`disassemble`] back into a program. Comments start
+/// with `;`, labels are any `name:` and can be used wherever an immediate value can.
+pub fn assemble(text: &str) -> Result<Vec<i64>, ParseError> {
+    // Each statement as (line number, mnemonic, operands with their columns).
+    let mut statements = Vec::new();
+    let mut labels = BTreeMap::new();
+    let mut address = 0;
+
+    for (number, line) in (1..).zip(text.lines()) {
+        let code = line.split(';').next().unwrap_or_default();
+        let mut rest = code;
+        let mut offset = 0;
+        if let Some((name, after)) = code.split_once(':') {
+            if labels.insert(name.trim(), address).is_some() {
+                let column = name.len() - name.trim_start().len() + 1;
+                return Err(ParseError::in_input(
+                    text,
+                    number,
+                    column,
+                    "a new label name",
+                ));
+            }
+            offset = name.len() + 1;
+            rest = after;
+        }
+        let trimmed = rest.trim_start();
+        if trimmed.trim_end().is_empty() {
+            continue;
+        }
+        let column = offset + rest.len() - trimmed.len() + 1;
+        let (mnemonic, operands) = trimmed
+            .trim_end()
+            .split_once(char::is_whitespace)
+            .unwrap_or((trimmed.trim_end(), ""));
+
+        let operands_column = column + mnemonic.len();
+        let mut parsed = Vec::new();
+        let mut position = operands_column;
+        if !operands.trim().is_empty() {
+            for piece in operands.split(',') {
+                let leading = piece.len() - piece.trim_start().len();
+                let operand_column = position + leading + 1;
+                let value = operand(piece).ok_or_else(|| {
+                    ParseError::in_input(text, number, operand_column, "an operand")
+                })?;
+                parsed.push((operand_column, value));
+                position += piece.len() + 1;
+            }
+        }
+
+        let size = if mnemonic == "data" {
+            parsed.len()
+        } else {
+            match INSTRUCTIONS.iter().find(|(_, name, ..)| *name == mnemonic) {
+                Some((_, _, count, _)) if *count == parsed.len() => count + 1,
+                Some((_, _, count, _)) => {
+                    let expected = format!("{} operands for `{}`", count, mnemonic);
+                    return Err(ParseError::in_input(text, number, column, expected));
+                }
+                None => return Err(ParseError::in_input(text, number, column, "an instruction")),
+            }
+        };
+        statements.push((number, mnemonic, parsed));
+        address += size;
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for (number, mnemonic, operands) in statements {
+        let mut values = Vec::with_capacity(operands.len());
+        let mut modes = 0;
+        for (index, (column, operand)) in operands.into_iter().enumerate() {
+            let (mode, value) = match operand {
+                Operand::Value(mode, value) => (mode, value),
+                Operand::Label(name) => match labels.get(name) {
+                    Some(address) => (1, *address as i64),
+                    None => {
+                        return Err(ParseError::in_input(
+                            text,
+                            number,
+                            column,
+                            "a defined label",
+                        ))
+                    }
+                },
+            };
+            if mnemonic == "data" && mode != 1 {
+                return Err(ParseError::in_input(text, number, column, "a value"));
+            }
+            modes += mode * 10_i64.pow(index as u32);
+            values.push(value);
+        }
+        if let Some((opcode, ..)) = INSTRUCTIONS.iter().find(|(_, name, ..)| *name == mnemonic) {
+            program.push(opcode + modes * 100);
+        }
+        program.extend(values);
+    }
+    Ok(program)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+    use crate::intcode::parse;
+
+    #[test]
+    fn test_disassemble() {
+        let program = [3, 9, 1005, 9, 7, 109, -2, 204, 1, 99, 42, 1234];
+        assert_eq!(
+            disassemble(&program),
+            "    in  [9]                             ; 0
+    jnz [9], L7                         ; 2
+    arb -2                              ; 5
+L7:
+    out [rb+1]                          ; 7
+    hlt                                 ; 9
+    data 42                             ; 10
+    data 1234                           ; 11
+"
+        );
+    }
+
+    #[test]
+    fn test_assemble() {
+        let listing = "
+start:  in [rb-1]
+        jz [rb], start   ; loop forever
+        data 7, -8
+";
+        assert_eq!(assemble(listing).unwrap(), vec![203, -1, 1206, 0, 0, 7, -8]);
+
+        let error = assemble("    jnz 1, nowhere\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 12));
+        assert_eq!(error.expected(), "a defined label");
+
+        let error = assemble("add 1, 2\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 1));
+        assert_eq!(error.expected(), "3 operands for `add`");
+    }
+
+    /// Inputs that are not Intcode programs: wire paths, orbit maps, image layers, asteroid
+    /// fields, saved memory dumps, and a program with a deliberate typo.
+    const NOT_INTCODE: &[&str] = &[
+        "2/python/input.results",
+        "2/rust/input.2",
+        "3/python/input",
+        "3/python/input.1",
+        "3/python/input.2",
+        "3/python/input.3",
+        "5/python/input.1.result",
+        "5/python/input.1.results",
+        "5/python/input.2.results",
+        "6/input.example",
+        "8/input",
+        "8/input.layered",
+        "9/input.1.result",
+        "9/input.1.result.expected",
+        "10/input.1",
+        "10/input.2",
+        "10/input.3",
+        "10/input.4",
+    ];
+
+    #[test]
+    fn test_round_trip_inputs() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let mut checked = 0;
+        let mut skipped = 0;
+        let mut pending = vec![root.clone()];
+        while let Some(directory) = pending.pop() {
+            for entry in fs::read_dir(directory).unwrap() {
+                let path = entry.unwrap().path();
+                let name = path.file_name().unwrap().to_string_lossy();
+                if path.is_dir() && name != "target" {
+                    pending.push(path);
+                } else if name.starts_with("input") {
+                    let relative = path.strip_prefix(&root).unwrap();
+                    if NOT_INTCODE.iter().any(|skip| relative == Path::new(skip)) {
+                        assert!(
+                            parse(&fs::read_to_string(&path).unwrap()).is_err(),
+                            "{} parses as Intcode",
+                            path.display()
+                        );
+                        skipped += 1;
+                        continue;
+                    }
+                    let text = fs::read_to_string(&path).unwrap();
+                    let program = parse(&text)
+                        .unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+                    let listing = disassemble(&program);
+                    assert_eq!(assemble(&listing).unwrap(), program, "{}", path.display());
+                    checked += 1;
+                }
+            }
+        }
+        assert_eq!(
+            skipped,
+            NOT_INTCODE.len(),
+            "some of NOT_INTCODE are missing"
+        );
+        assert!(checked > 20, "only {} programs found", checked);
+    }
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+
+use advent_support::read_input_string;
+use aoc2019_intcode::assemble;
+
+/// Turn a listing read from stdin back into a comma separated Intcode program.
+fn main() -> Result<()> {
+    let program = assemble(&read_input_string()?)?;
+    let values: Vec<String> = program.iter().map(|value| value.to_string()).collect();
+    println!("{}", values.join(","));
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+
+use advent_support::read_input_string;
+use aoc2019_intcode::{disassemble, parse};
+
+/// Print a listing of the Intcode program read from stdin.
+fn main() -> Result<()> {
+    let program = parse(&read_input_string()?)?;
+    print!("{}", disassemble(&program));
+    Ok(())
+}
@@ -0,0 +1,444 @@
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt;
+
+use advent_support::ParseError;
+
+/// Read a comma separated program. Whitespace and newlines between values are ignored, as is a
+/// trailing comma.
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut program = Vec::new();
+    for (number, line) in (1..).zip(input.lines()) {
+        let mut column = 1;
+        for value in line.split(',') {
+            let trimmed = value.trim();
+            if !trimmed.is_empty() {
+                let leading = value.len() - value.trim_start().len();
+                let parsed = trimmed.parse().map_err(|_| {
+                    ParseError::in_input(input, number, column + leading, "an integer")
+                })?;
+                program.push(parsed);
+            }
+            column += value.len() + 1;
+        }
+    }
+    Ok(program)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntcodeError {
+    UnknownOpcode {
+        address: usize,
+        opcode: i64,
+    },
+    UnknownMode {
+        address: usize,
+        mode: i64,
+    },
+    /// An instruction tried to write to an immediate mode parameter.
+    ImmediateWrite {
+        address: usize,
+    },
+    /// A parameter pointed at a negative address.
+    NegativeAddress {
+        address: usize,
+        target: i64,
+    },
+    /// An input instruction ran with nothing queued.
+    MissingInput {
+        address: usize,
+    },
+    /// An addition or multiplication, or the relative base, went past the range of an `i64`.
+    Overflow {
+        address: usize,
+    },
+}
+
+impl fmt::Display for IntcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntcodeError::UnknownOpcode { address, opcode } => {
+                write!(f, "unknown opcode {} @ {}", opcode, address)
+            }
+            IntcodeError::UnknownMode { address, mode } => {
+                write!(f, "unknown parameter mode {} @ {}", mode, address)
+            }
+            IntcodeError::ImmediateWrite { address } => {
+                write!(f, "write to an immediate parameter @ {}", address)
+            }
+            IntcodeError::NegativeAddress { address, target } => {
+                write!(f, "negative address {} @ {}", target, address)
+            }
+            IntcodeError::MissingInput { address } => {
+                write!(f, "input needed but none is queued @ {}", address)
+            }
+            IntcodeError::Overflow { address } => {
+                write!(f, "arithmetic overflow @ {}", address)
+            }
+        }
+    }
+}
+
+impl Error for IntcodeError {}
+
+/// Why [`Intcode::resume`] returned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum State {
+    /// The next instruction reads input and none is queued.
+    NeedsInput,
+    Output(i64),
+    Halted,
+}
+
+/// Memory below this address, or within the program, is kept in a `Vec`. Writes above it go to
+/// a map instead, so a program cannot make the machine allocate its whole address space.
+const DENSE_LIMIT: usize = 1 << 20;
+
+/// An Intcode computer. Memory starts as a copy of the program and grows, zero filled, whenever
+/// an address past the end is written, up to `DENSE_LIMIT`.
+#[derive(Clone, Debug)]
+pub struct Intcode {
+    memory: Vec<i64>,
+    /// Written addresses at or past both `DENSE_LIMIT` and the end of `memory`.
+    sparse: HashMap<usize, i64>,
+    pc: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+    output: VecDeque<i64>,
+    halted: bool,
+}
+
+impl Intcode {
+    pub fn new(program: &[i64]) -> Self {
+        Self {
+            memory: program.to_vec(),
+            sparse: HashMap::new(),
+            pc: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+            halted: false,
+        }
+    }
+
+    /// The program and any memory written up to `DENSE_LIMIT`, but not writes beyond it.
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    /// The value at `address`, where anything never written is zero.
+    pub fn read(&self, address: usize) -> i64 {
+        match self.memory.get(address) {
+            Some(value) => *value,
+            None => self.sparse.get(&address).copied().unwrap_or(0),
+        }
+    }
+
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address < self.memory.len() {
+            self.memory[address] = value;
+        } else if address < DENSE_LIMIT {
+            self.memory.resize(address + 1, 0);
+            self.memory[address] = value;
+        } else {
+            self.sparse.insert(address, value);
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn pop_output(&mut self) -> Option<i64> {
+        self.output.pop_front()
+    }
+
+    /// Remove and return everything output so far.
+    pub fn take_output(&mut self) -> Vec<i64> {
+        self.output.drain(..).collect()
+    }
+
+    /// Run until the program halts.
+    pub fn run(&mut self) -> Result<(), IntcodeError> {
+        while !self.halted {
+            self.step()?;
+        }
+        Ok(())
+    }
+
+    /// Run until the program produces output, needs input that has not been queued yet, or
+    /// halts. Calling it again carries on from the same spot, so several machines can be driven
+    /// in turn by a single loop.
+    pub fn resume(&mut self) -> Result<State, IntcodeError> {
+        loop {
+            if self.halted {
+                return Ok(State::Halted);
+            }
+            let opcode = self.read(self.pc) % 100;
+            if opcode == 3 && self.input.is_empty() {
+                return Ok(State::NeedsInput);
+            }
+            self.step()?;
+            if let (4, Some(value)) = (opcode, self.output.pop_back()) {
+                return Ok(State::Output(value));
+            }
+        }
+    }
+
+    /// Execute a single instruction.
+    pub fn step(&mut self) -> Result<(), IntcodeError> {
+        let instruction = self.read(self.pc);
+        let opcode = instruction % 100;
+        let next = match opcode {
+            1 | 2 | 7 | 8 => {
+                let first = self.parameter(1)?;
+                let second = self.parameter(2)?;
+                let result = match opcode {
+                    1 => first.checked_add(second),
+                    2 => first.checked_mul(second),
+                    7 => Some((first < second) as i64),
+                    _ => Some((first == second) as i64),
+                }
+                .ok_or(IntcodeError::Overflow { address: self.pc })?;
+                let target = self.target(3)?;
+                self.write(target, result);
+                self.pc + 4
+            }
+            3 => {
+                let value = self
+                    .input
+                    .pop_front()
+                    .ok_or(IntcodeError::MissingInput { address: self.pc })?;
+                let target = self.target(1)?;
+                self.write(target, value);
+                self.pc + 2
+            }
+            4 => {
+                let value = self.parameter(1)?;
+                self.output.push_back(value);
+                self.pc + 2
+            }
+            5 | 6 => {
+                let condition = self.parameter(1)?;
+                if (condition != 0) == (opcode == 5) {
+                    self.address(self.parameter(2)?)?
+                } else {
+                    self.pc + 3
+                }
+            }
+            9 => {
+                self.relative_base = self
+                    .relative_base
+                    .checked_add(self.parameter(1)?)
+                    .ok_or(IntcodeError::Overflow { address: self.pc })?;
+                self.pc + 2
+            }
+            99 => {
+                self.halted = true;
+                self.pc
+            }
+            _ => {
+                return Err(IntcodeError::UnknownOpcode {
+                    address: self.pc,
+                    opcode: instruction,
+                })
+            }
+        };
+        self.pc = next;
+        Ok(())
+    }
+
+    /// The mode of parameter `index`, counting from 1.
+    fn mode(&self, index: u32) -> i64 {
+        self.read(self.pc) / 10_i64.pow(index + 1) % 10
+    }
+
+    fn parameter(&self, index: u32) -> Result<i64, IntcodeError> {
+        let raw = self.read(self.pc + index as usize);
+        match self.mode(index) {
+            1 => Ok(raw),
+            _ => Ok(self.read(self.target(index)?)),
+        }
+    }
+
+    /// The address a position or relative mode parameter refers to.
+    fn target(&self, index: u32) -> Result<usize, IntcodeError> {
+        let raw = self.read(self.pc + index as usize);
+        match self.mode(index) {
+            0 => self.address(raw),
+            1 => Err(IntcodeError::ImmediateWrite { address: self.pc }),
+            2 => self.address(
+                self.relative_base
+                    .checked_add(raw)
+                    .ok_or(IntcodeError::Overflow { address: self.pc })?,
+            ),
+            mode => Err(IntcodeError::UnknownMode {
+                address: self.pc,
+                mode,
+            }),
+        }
+    }
+
+    fn address(&self, target: i64) -> Result<usize, IntcodeError> {
+        usize::try_from(target).map_err(|_| IntcodeError::NegativeAddress {
+            address: self.pc,
+            target,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> Vec<i64> {
+        let path = format!("{}/../../{}", env!("CARGO_MANIFEST_DIR"), name);
+        parse(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    fn run_with(program: &[i64], input: &[i64]) -> Vec<i64> {
+        let mut machine = Intcode::new(program);
+        for value in input {
+            machine.push_input(*value);
+        }
+        machine.run().unwrap();
+        machine.take_output()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("1,0,\n0,3,\n99\n").unwrap(), vec![1, 0, 0, 3, 99]);
+
+        let error = parse("1,0,\n0, x,99\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 4));
+    }
+
+    #[test]
+    fn test_simple() {
+        let mut machine = Intcode::new(&fixture("9/input.simple"));
+        machine.run().unwrap();
+        assert_eq!(machine.read(5), 10);
+    }
+
+    #[test]
+    fn test_day2() {
+        let mut machine = Intcode::new(&fixture("2/rust/input.3"));
+        machine.run().unwrap();
+        assert_eq!(machine.read(0), 4330636);
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        assert_eq!(
+            run_with(&fixture("9/input.large_num"), &[]),
+            vec![1125899906842624]
+        );
+        let output = run_with(&fixture("9/input.16digit"), &[]);
+        assert_eq!(output[0].to_string().len(), 16);
+    }
+
+    #[test]
+    fn test_relative() {
+        let mut machine = Intcode::new(&fixture("9/input.relative"));
+        machine.push_input(42);
+        machine.run().unwrap();
+        assert_eq!(machine.read(6), 42);
+
+        // Writes well past the end of the program, then runs off the end into zeroed memory.
+        let mut machine = Intcode::new(&fixture("9/input.relative.2"));
+        assert_eq!(
+            machine.run(),
+            Err(IntcodeError::UnknownOpcode {
+                address: 8,
+                opcode: 0
+            })
+        );
+        assert_eq!(machine.take_output(), vec![1234]);
+    }
+
+    #[test]
+    fn test_replicate() {
+        let program = fixture("9/input.replicate");
+        assert_eq!(run_with(&program, &[]), program);
+    }
+
+    #[test]
+    fn test_comparisons_and_jumps() {
+        // Both output 1 if the input equals 8, otherwise 0.
+        let equal = [3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8];
+        assert_eq!(run_with(&equal, &[8]), vec![1]);
+        assert_eq!(run_with(&equal, &[7]), vec![0]);
+        let jump = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+        assert_eq!(run_with(&jump, &[0]), vec![0]);
+        assert_eq!(run_with(&jump, &[5]), vec![1]);
+    }
+
+    #[test]
+    fn test_resume() {
+        // Reads numbers and outputs each one doubled, until given zero.
+        let program = [3, 20, 1006, 20, 14, 1002, 20, 2, 21, 4, 21, 1105, 1, 0, 99];
+        let mut machine = Intcode::new(&program);
+        let mut outputs = Vec::new();
+        let mut next = 1;
+        loop {
+            match machine.resume().unwrap() {
+                State::NeedsInput if next <= 3 => {
+                    machine.push_input(next);
+                    next += 1;
+                }
+                State::NeedsInput => machine.push_input(0),
+                State::Output(value) => outputs.push(value),
+                State::Halted => break,
+            }
+        }
+        assert_eq!(outputs, vec![2, 4, 6]);
+        assert!(machine.take_output().is_empty());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut machine = Intcode::new(&[3, 0, 99]);
+        assert_eq!(
+            machine.run(),
+            Err(IntcodeError::MissingInput { address: 0 })
+        );
+
+        let mut machine = Intcode::new(&[1101, 1, 1, -1, 99]);
+        assert_eq!(
+            machine.run(),
+            Err(IntcodeError::NegativeAddress {
+                address: 0,
+                target: -1
+            })
+        );
+
+        let mut machine = Intcode::new(&[11101, 1, 1, 0, 99]);
+        assert_eq!(
+            machine.run(),
+            Err(IntcodeError::ImmediateWrite { address: 0 })
+        );
+
+        let mut machine = Intcode::new(&[1101, i64::MAX, 1, 0, 99]);
+        assert_eq!(machine.run(), Err(IntcodeError::Overflow { address: 0 }));
+        let mut machine = Intcode::new(&[1102, i64::MAX, 2, 0, 99]);
+        assert_eq!(machine.run(), Err(IntcodeError::Overflow { address: 0 }));
+        let mut machine = Intcode::new(&[109, i64::MAX, 109, 1, 99]);
+        assert_eq!(machine.run(), Err(IntcodeError::Overflow { address: 2 }));
+    }
+
+    #[test]
+    fn test_far_addresses() {
+        // Copies 7 to 2^40 and back to 0, without allocating everything in between.
+        let far = 1 << 40;
+        let mut machine = Intcode::new(&[1101, 7, 0, far, 1001, far, 0, 0, 99]);
+        machine.run().unwrap();
+        assert_eq!(machine.read(0), 7);
+        assert_eq!(machine.read(far as usize), 7);
+        assert_eq!(machine.read(far as usize + 1), 0);
+        assert_eq!(machine.memory().len(), 9);
+    }
+}
@@ -0,0 +1,67 @@
+mod amplifier;
+mod assembly;
+mod intcode;
+
+use advent_support::Solution;
+use anyhow::{anyhow, bail, Result};
+
+pub use crate::amplifier::{best_phases, run_amplifiers, Day7};
+pub use crate::assembly::{assemble, disassemble};
+pub use crate::intcode::{parse, Intcode, IntcodeError, State};
+
+/// Run the program with `noun` and `verb` in addresses 1 and 2, returning address 0.
+fn run_gravity_assist(program: &[i64], noun: i64, verb: i64) -> Result<i64> {
+    let mut machine = Intcode::new(program);
+    machine.write(1, noun);
+    machine.write(2, verb);
+    machine.run()?;
+    Ok(machine.read(0))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(run_gravity_assist(&parse(input)?, 12, 2)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let program = parse(input)?;
+        for noun in 0..100 {
+            for verb in 0..100 {
+                if run_gravity_assist(&program, noun, verb)? == 19690720 {
+                    return Ok((100 * noun + verb).to_string());
+                }
+            }
+        }
+        bail!("no noun and verb produce 19690720")
+    }
+}
+
+/// Run the diagnostic program for system `id`, returning the final output. Every earlier output
+/// is a test result and must be zero.
+fn run_diagnostic(program: &[i64], id: i64) -> Result<i64> {
+    let mut machine = Intcode::new(program);
+    machine.push_input(id);
+    machine.run()?;
+    let output = machine.take_output();
+    let (code, tests) = output
+        .split_last()
+        .ok_or_else(|| anyhow!("no diagnostic code"))?;
+    if let Some(failed) = tests.iter().position(|value| *value != 0) {
+        bail!("diagnostic test {} failed with {}", failed, tests[failed]);
+    }
+    Ok(*code)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(run_diagnostic(&parse(input)?, 1)?.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(run_diagnostic(&parse(input)?, 5)?.to_string())
+    }
+}
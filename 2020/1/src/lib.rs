@@ -0,0 +1,32 @@
+use std::convert::TryInto;
+
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+const DESIRED_SUM: i32 = 2020;
+
+pub fn get_when_sum(values: &[i32], number_of_elements: u32, target: i32) -> Option<Vec<i32>> {
+    let it = values.iter().cloned();
+    let perms = it.permutations(number_of_elements.try_into().unwrap());
+    perms.filter(|p| p.iter().sum::<i32>() == target).take(1).next()
+}
+
+fn product_of_entries(input: &str, number_of_elements: u32) -> Result<String> {
+    let numbers: Vec<i32> = advent_support::parse_lines(input)?;
+    get_when_sum(&numbers, number_of_elements, DESIRED_SUM)
+        .map(|v| v.iter().product::<i32>().to_string())
+        .ok_or_else(|| anyhow!("Not found"))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> Result<String> {
+        product_of_entries(input, 2)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        product_of_entries(input, 3)
+    }
+}
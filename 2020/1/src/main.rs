@@ -1,19 +1,8 @@
-use std::convert::TryInto;
 use std::env;
-use std::io::{self, BufRead};
-use itertools::Itertools;
 
-fn get_when_sum(values: &[i32], number_of_elements: u32, target: i32) -> Option<Vec<i32>> {
-    let it = values.iter().cloned();
-    let perms = it.permutations(number_of_elements.try_into().unwrap());
-    perms.filter(|p| p.iter().sum::<i32>() == target).take(1).next()
-}
+use advent_support::read_input;
 
-fn read_input() -> Vec<i32> {
-    let stdin = io::stdin();
-    let handle = stdin.lock();
-    handle.lines().map(|x| x.unwrap().parse::<i32>().unwrap()).collect()
-}
+use aoc2020_day1::get_when_sum;
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -24,7 +13,7 @@ fn main() {
             panic!("Usage: need number of elements and expected sum");
         }
     };
-    let numbers = read_input();
+    let numbers = read_input::<i32>().unwrap();
     match get_when_sum(&numbers, number_of_elements, desired_sum) {
         Some(v) => { println!("{:?} = {}", v, v.iter().product::<i32>()); }
         _ => { println!("Not found"); }
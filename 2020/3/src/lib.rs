@@ -0,0 +1,50 @@
+use advent_support::{Grid, Solution};
+use anyhow::{bail, Result};
+
+#[derive(Clone, Copy, Debug)]
+enum MapElement {
+    Open,
+    Tree,
+}
+
+fn parse_map(input: &str) -> Result<Grid<MapElement>> {
+    Grid::parse(input, |c| match c {
+        '.' => Ok(MapElement::Open),
+        '#' => Ok(MapElement::Tree),
+        _ => bail!("What?? {}", c),
+    })
+}
+
+fn compute_path(right: usize, down: usize, map: &Grid<MapElement>) -> Vec<MapElement> {
+    (0..map.height())
+        .step_by(down)
+        .enumerate()
+        .filter_map(|(step, y)| map.get_wrapping((step * right) as isize, y as isize))
+        .copied()
+        .collect()
+}
+
+fn count_trees(right: usize, down: usize, map: &Grid<MapElement>) -> usize {
+    compute_path(right, down, map)
+        .iter()
+        .filter(|x| matches!(x, MapElement::Tree))
+        .count()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let map = parse_map(input)?;
+        Ok(count_trees(3, 1, &map).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let map = parse_map(input)?;
+        let trees_product: usize = vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .into_iter()
+            .map(|(right, down)| count_trees(right, down, &map))
+            .product();
+        Ok(trees_product.to_string())
+    }
+}
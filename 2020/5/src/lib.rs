@@ -0,0 +1,115 @@
+use std::cmp::max;
+
+use advent_support::{ParseError, Solution};
+use anyhow::{anyhow, Result};
+
+fn parse_value(value: &str, max: u32, lower: char, upper: char) -> u32 {
+    let mut pos = 0;
+    let mut current_max = max;
+    for c in value.chars() {
+        //dbg!(c, pos, current_max);
+        if c == lower {
+            current_max = ((current_max - pos) / 2) + pos;
+        } else if c == upper {
+            pos += (current_max - pos) / 2;
+        }
+    }
+    pos
+}
+
+/// Check that a boarding pass is seven of `F`/`B` followed by three of `L`/`R`, returning the
+/// 1-based column and expectation of the first problem.
+fn validate(value: &str) -> Result<(), (usize, &'static str)> {
+    let chars: Vec<char> = value.chars().collect();
+    for index in 0..10 {
+        let (allowed, expected) = if index < 7 {
+            (['F', 'B'], "`F` or `B`")
+        } else {
+            (['L', 'R'], "`L` or `R`")
+        };
+        if !chars.get(index).is_some_and(|c| allowed.contains(c)) {
+            return Err((index + 1, expected));
+        }
+    }
+    if chars.len() > 10 {
+        return Err((11, "end of line"));
+    }
+    Ok(())
+}
+
+fn parse_row_and_column(value: &str) -> (u32, u32) {
+    let row_info = &value[0..7];
+    let column_info = &value[7..10];
+
+    (
+        parse_value(row_info, 128, 'F', 'B'),
+        parse_value(column_info, 8, 'L', 'R'),
+    )
+}
+
+const fn compute_seat_id(row: u32, column: u32) -> u32 {
+    (row * 8) + column
+}
+
+fn seat_ids(input: &str) -> Result<Vec<u32>, ParseError> {
+    (1..)
+        .zip(input.lines())
+        .map(|(number, line)| {
+            validate(line).map_err(|(column, expected)| {
+                ParseError::in_input(input, number, column, expected)
+            })?;
+            let (row, column) = parse_row_and_column(line);
+            Ok(compute_seat_id(row, column))
+        })
+        .collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let max_id = seat_ids(input)?.into_iter().fold(0, max);
+        Ok(max_id.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let mut seen_ids = seat_ids(input)?;
+        seen_ids.sort();
+
+        let mut last = 7;
+        for seat in seen_ids {
+            if seat - last == 2 {
+                return Ok((seat - 1).to_string());
+            }
+            last = seat;
+        }
+        Err(anyhow!("no empty seat"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seat_ids() {
+        assert_eq!(
+            seat_ids("BFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n").unwrap(),
+            vec![567, 119, 820]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = seat_ids("BFFFBBFRRR\nFFFBBBFRXR\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 9));
+        assert_eq!(error.expected(), "`L` or `R`");
+
+        let error = seat_ids("BFFFB\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 6));
+        assert_eq!(error.expected(), "`F` or `B`");
+
+        let error = seat_ids("BFFFBBFRRRR\n").unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 11));
+    }
+}
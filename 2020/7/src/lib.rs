@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+use petgraph::graph::{DiGraph, NodeIndex};
+use petgraph::visit::{Bfs, Reversed};
+
+const MY_BAG: &str = "shiny gold";
+
+fn node_for(
+    graph: &mut DiGraph<String, ()>,
+    nodes: &mut HashMap<String, NodeIndex>,
+    bag_color: String,
+) -> NodeIndex {
+    *nodes
+        .entry(bag_color)
+        .or_insert_with_key(|bag_color| graph.add_node(bag_color.clone()))
+}
+
+// Walk the input, turn into a graph with connection indicating bags inside.
+// no other bags means no connections on the graph
+//
+// What bags can hold a specific bag is determined by walking the edges backwards.
+fn process_input(input: &str) -> Result<(HashMap<String, NodeIndex>, DiGraph<String, ()>)> {
+    let mut graph: DiGraph<String, ()> = DiGraph::new();
+    let mut nodes = HashMap::new();
+
+    for line in input.lines() {
+        let (container, contained) = line
+            .split_once("contain")
+            .ok_or_else(|| anyhow!("expected `<bag> contain <bags>`, found {:?}", line))?;
+        let bag_color = container.trim().trim_end_matches(" bags").to_string();
+        let node = node_for(&mut graph, &mut nodes, bag_color);
+
+        let contained = contained.trim();
+        if contained.starts_with("no ") {
+            continue;
+        }
+        for bag in contained.split(", ") {
+            let pieces = bag.split(' ').collect::<Vec<_>>();
+            if pieces.len() < 3 {
+                return Err(anyhow!("expected `<count> <color> bags`, found {:?}", bag));
+            }
+            let bag_node = node_for(&mut graph, &mut nodes, pieces[1..3].join(" "));
+            graph.add_edge(node, bag_node, ());
+        }
+    }
+
+    Ok((nodes, graph))
+}
+
+/// How many bag colors can end up holding a `color` bag.
+fn count_containers(input: &str, color: &str) -> Result<usize> {
+    let (nodes, graph) = process_input(input)?;
+    let start = *nodes
+        .get(color)
+        .ok_or_else(|| anyhow!("no rule mentions {} bags", color))?;
+
+    let reversed = Reversed(&graph);
+    let mut search = Bfs::new(reversed, start);
+    let mut count = 0;
+    while let Some(node) = search.next(reversed) {
+        if node != start {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(count_containers(input, MY_BAG)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_containers() {
+        let input = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+        assert_eq!(count_containers(input, MY_BAG).unwrap(), 4);
+        assert!(count_containers("shiny gold bags hold everything\n", MY_BAG).is_err());
+    }
+}
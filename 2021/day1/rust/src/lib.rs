@@ -0,0 +1,38 @@
+use advent_support::{parse_lines, Solution};
+use anyhow::Result;
+
+fn count_increases(depths: &[i32]) -> usize {
+    depths.windows(2).filter(|pair| pair[1] > pair[0]).count()
+}
+
+fn count_window_increases(depths: &[i32]) -> usize {
+    let sums: Vec<i32> = depths.windows(3).map(|window| window.iter().sum()).collect();
+    count_increases(&sums)
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let depths: Vec<i32> = parse_lines(input)?;
+        Ok(count_increases(&depths).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let depths: Vec<i32> = parse_lines(input)?;
+        Ok(count_window_increases(&depths).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Day1.part1(EXAMPLE).unwrap(), "7");
+        assert_eq!(Day1.part2(EXAMPLE).unwrap(), "5");
+    }
+}
@@ -1,32 +1,10 @@
-use std::io::{self, BufRead};
+use advent_support::Solution;
+use anyhow::Result;
 
-pub fn read_input() -> Vec<(String, i32)>
-{
-    let stdin = io::stdin();
-    let handle = stdin.lock();
+use aoc2021_day2::Day2;
 
-    handle.lines().map(|line| {
-        let line = line.unwrap();
-        let (action, distance) = line.split_once(' ').unwrap();
-        let distance = distance.parse::<i32>().unwrap();
-        (action.to_string(), distance)
-    }).collect()
-}
-
-fn main() {
-    let input = read_input();
-
-    let mut horizontal = 0;
-    let mut depth = 0;
-    let mut aim = 0;
-
-    for (action, distance) in input {
-        match action.as_str() {
-            "forward" => { horizontal += distance; depth += aim * distance; },
-            "up" => { aim -= distance; },
-            "down" => { aim += distance; },
-            _ => { unreachable!(); },
-        }
-    }
-    println!("{}, {}: {}", horizontal, depth, horizontal * depth);
+fn main() -> Result<()> {
+    let input = advent_support::read_input::<String>()?.join("\n");
+    println!("{}", Day2.part2(&input)?);
+    Ok(())
 }
@@ -0,0 +1,49 @@
+use advent_support::Solution;
+use anyhow::{anyhow, bail, Result};
+
+fn parse_commands(input: &str) -> Result<Vec<(String, i32)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (action, distance) = line
+                .split_once(' ')
+                .ok_or_else(|| anyhow!("invalid command: {}", line))?;
+            Ok((action.to_string(), distance.parse::<i32>()?))
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let mut horizontal = 0;
+        let mut depth = 0;
+
+        for (action, distance) in parse_commands(input)? {
+            match action.as_str() {
+                "forward" => { horizontal += distance; },
+                "up" => { depth -= distance; },
+                "down" => { depth += distance; },
+                _ => bail!("unknown action: {}", action),
+            }
+        }
+        Ok((horizontal * depth).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let mut horizontal = 0;
+        let mut depth = 0;
+        let mut aim = 0;
+
+        for (action, distance) in parse_commands(input)? {
+            match action.as_str() {
+                "forward" => { horizontal += distance; depth += aim * distance; },
+                "up" => { aim -= distance; },
+                "down" => { aim += distance; },
+                _ => bail!("unknown action: {}", action),
+            }
+        }
+        Ok((horizontal * depth).to_string())
+    }
+}
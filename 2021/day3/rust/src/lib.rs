@@ -0,0 +1,64 @@
+use advent_support::Solution;
+use anyhow::Result;
+
+/*
+for each binary
+   for each bit
+     increment either 1 or 0 count for this bit
+
+examine bit counts
+for each bit
+    if 1 > 0
+      put 1 on gamma, 0 on epsilon
+    else
+      put 0 on gamma, 1 on epsilon
+ */
+fn power_consumption(input: &str) -> u32 {
+    let width = input.lines().next().map_or(0, str::len);
+    let mut ones = vec![0u32; width];
+    let mut zeroes = vec![0u32; width];
+
+    for line in input.lines() {
+        for (index, bit) in line.chars().enumerate() {
+            match bit {
+                '0' => { zeroes[index] += 1; },
+                '1' => { ones[index] += 1; },
+                _ => { unreachable!(); },
+            }
+        }
+    }
+
+    let mut gamma = 0;
+    let mut epsilon = 0;
+
+    // The first column is the most significant bit.
+    for index in 0..width {
+        let bit = 1 << (width - index - 1);
+        if ones[index] > zeroes[index] {
+            gamma |= bit;
+        } else {
+            epsilon |= bit;
+        }
+    }
+
+    gamma * epsilon
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(power_consumption(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+        assert_eq!(power_consumption(input), 198);
+    }
+}
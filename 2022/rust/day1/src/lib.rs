@@ -0,0 +1,36 @@
+use std::collections::BinaryHeap;
+
+use advent_support::Solution;
+use anyhow::Result;
+use itertools::Itertools;
+
+type Food = u32;
+type Elf = Vec<Food>;
+
+fn process_input(input: &str) -> Vec<Elf> {
+    input
+        .lines()
+        .map(|x| x.parse::<Food>())
+        .group_by(|x| x.is_ok())
+        .into_iter()
+        .filter_map(|(key, group)| key.then(|| group.collect::<Result<_, _>>().unwrap()))
+        .collect()
+}
+
+fn total_calories_per_elf(input: &str) -> BinaryHeap<Food> {
+    process_input(input).into_iter().map(|x| x.iter().sum()).collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let total_calories_per_elf = total_calories_per_elf(input);
+        Ok(total_calories_per_elf.peek().copied().unwrap_or_default().to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let total_calories_per_elf = total_calories_per_elf(input).into_sorted_vec();
+        Ok(total_calories_per_elf.iter().rev().take(3).sum::<Food>().to_string())
+    }
+}
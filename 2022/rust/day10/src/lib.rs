@@ -0,0 +1,219 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use advent_support::{
+    read_letters, Animation, Flow, Grid, Image, Instruction, Machine, Observer, Registers,
+    Solution,
+};
+use anyhow::{anyhow, Result};
+
+#[derive(Copy, Clone, Debug)]
+enum CpuOpcode {
+    Noop,
+    Addx(i64),
+}
+
+impl FromStr for CpuOpcode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pieces: Vec<_> = s.split(' ').collect();
+        match pieces.as_slice() {
+            ["noop"] => Ok(Self::Noop),
+            ["addx", num] => Ok(Self::Addx(num.parse::<i64>().map_err(|_| ())?)),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The only register, `x`.
+const X: usize = 0;
+
+impl Instruction for CpuOpcode {
+    fn cycles(&self) -> usize {
+        match self {
+            CpuOpcode::Noop => 1,
+            CpuOpcode::Addx(_) => 2,
+        }
+    }
+
+    fn execute(&self, registers: &mut Registers) -> Flow {
+        if let CpuOpcode::Addx(value) = self {
+            registers[X] += value;
+        }
+        Flow::Next
+    }
+}
+
+fn run(opcodes: Vec<CpuOpcode>, observers: &mut [&mut dyn Observer]) {
+    let mut registers = Registers::new(&["x"]);
+    registers[X] = 1;
+    Machine::new(opcodes, registers)
+        .run_observed(observers)
+        .expect("no jumps");
+}
+
+/// Sums the signal strength during cycles 20, 60, 100, 140, 180 and 220.
+#[derive(Default)]
+struct SignalStrength {
+    total: i64,
+}
+
+impl Observer for SignalStrength {
+    fn on_cycle(&mut self, cycle: usize, registers: &Registers) {
+        if [20, 60, 100, 140, 180, 220].contains(&cycle) {
+            self.total += cycle as i64 * registers[X];
+        }
+    }
+}
+
+fn draw(screen: &Grid<char>) -> Image {
+    Image::from_grid(screen, 10, |pixel| if *pixel == '#' { 255 } else { 0 })
+}
+
+/// Draws one pixel per cycle, lit when the three pixel wide sprite centered on `x` covers it.
+struct Crt {
+    screen: Grid<char>,
+    /// The screen after every cycle, when recording.
+    frames: Option<Vec<Image>>,
+}
+
+impl Crt {
+    fn new() -> Self {
+        Self {
+            screen: Grid::new(40, 6, '.'),
+            frames: None,
+        }
+    }
+
+    fn recording() -> Self {
+        Self {
+            frames: Some(Vec::new()),
+            ..Self::new()
+        }
+    }
+
+    fn image(&self) -> Image {
+        draw(&self.screen)
+    }
+}
+
+impl Observer for Crt {
+    fn on_cycle(&mut self, cycle: usize, registers: &Registers) {
+        let position = (cycle - 1) % 40;
+        let sprite = registers[X];
+        if ((sprite - 1)..=(sprite + 1)).contains(&(position as i64)) {
+            self.screen[(position, (cycle - 1) / 40)] = '#';
+        }
+        if let Some(frames) = &mut self.frames {
+            frames.push(draw(&self.screen));
+        }
+    }
+}
+
+fn part1(opcodes: Vec<CpuOpcode>) -> i64 {
+    let mut signal = SignalStrength::default();
+    run(opcodes, &mut [&mut signal]);
+    signal.total
+}
+
+/// The letters on the screen, or the screen itself when they cannot all be read.
+fn part2(opcodes: Vec<CpuOpcode>) -> String {
+    let mut crt = Crt::new();
+    run(opcodes, &mut [&mut crt]);
+    let letters = read_letters(&crt.screen, |pixel| *pixel == '#');
+    if letters.contains('?') {
+        crt.screen.to_string()
+    } else {
+        letters
+    }
+}
+
+/// Draw the final screen as a PNG or PPM, or the screen after every cycle as a GIF, depending on
+/// the extension of `path`.
+pub fn render(input: &str, path: &Path) -> Result<()> {
+    let opcodes = parse_input(input)?;
+    if path.extension().is_some_and(|extension| extension == "gif") {
+        let mut crt = Crt::recording();
+        run(opcodes, &mut [&mut crt]);
+        let size = crt.image();
+        let mut animation = Animation::create(path, size.width(), size.height(), 4)?;
+        for frame in crt.frames.iter().flatten() {
+            animation.add(frame)?;
+        }
+        Ok(())
+    } else {
+        let mut crt = Crt::new();
+        run(opcodes, &mut [&mut crt]);
+        crt.image().save(path)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<CpuOpcode>> {
+    input
+        .lines()
+        .map(|line| {
+            line.parse::<CpuOpcode>()
+                .map_err(|_| anyhow!("invalid opcode: {}", line))
+        })
+        .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(parse_input(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(parse_input(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let data = include_str!("example.input");
+        let opcodes = parse_input(data).unwrap();
+
+        let mut signal = SignalStrength::default();
+        let mut crt = Crt::new();
+        run(opcodes, &mut [&mut signal, &mut crt]);
+
+        assert_eq!(signal.total, 13140);
+
+        let expected = [
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ];
+        assert_eq!(crt.screen.to_string(), expected.join("\n"));
+
+        // Stripes, not letters, so the screen is the answer.
+        let opcodes = parse_input(data).unwrap();
+        assert_eq!(part2(opcodes), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_recording() {
+        let opcodes = parse_input(include_str!("example.input")).unwrap();
+        let mut crt = Crt::recording();
+        run(opcodes, &mut [&mut crt]);
+
+        let frames = crt.frames.take().unwrap();
+        assert_eq!(frames.len(), 240);
+        assert_eq!(frames.last(), Some(&crt.image()));
+        // The first cycle lights the top left pixel and nothing else.
+        assert_eq!(
+            frames[0].pixels().iter().filter(|v| **v == 255).count(),
+            100
+        );
+    }
+}
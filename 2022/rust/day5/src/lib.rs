@@ -0,0 +1,129 @@
+use std::fmt::Debug;
+
+use advent_support::Solution;
+use anyhow::Result;
+
+#[derive(Clone, Copy, Debug)]
+struct Move {
+    count: usize,
+    source: usize,
+    destination: usize,
+}
+
+impl Move {
+    fn new(data: &[usize]) -> Self {
+        Move {
+            count: data[0],
+            source: data[1] - 1,
+            destination: data[2] - 1,
+        }
+    }
+}
+
+type Crate = char;
+type Stack = Vec<Crate>;
+
+fn parse_input(input: &str) -> (Vec<Stack>, Vec<Move>) {
+    let mut lines = input.lines();
+
+    let mut initial_stacks: Vec<Stack> = Vec::new();
+    for line in lines.by_ref() {
+        if line.starts_with(" 1") {
+            break;
+        }
+        let chars: Stack = line.chars().collect();
+        let row: Stack = (0..=(chars.len() / 4))
+            .map(|i| chars[(i * 4) + 1])
+            .collect();
+        initial_stacks.push(row);
+    }
+
+    // The rest of the input is moves.
+    let moves = lines
+        .filter_map(|line| {
+            let data: Vec<_> = line
+                .split(' ')
+                .filter_map(|x| x.parse::<usize>().ok())
+                .collect();
+            if data.is_empty() {
+                None
+            } else {
+                Some(Move::new(&data))
+            }
+        })
+        .collect();
+
+    (initial_stacks, moves)
+}
+
+fn transpose(v: &[Stack]) -> Vec<Stack> {
+    let len = v[0].len();
+    let mut iters: Vec<_> = v.iter().map(|n| n.iter()).collect();
+    (0..len)
+        .map(|_| {
+            iters
+                .iter_mut()
+                .filter_map(|n| match n.next().unwrap() {
+                    // Now to drop empty elements in a stack.
+                    ' ' => None,
+                    value => Some(value),
+                })
+                .copied()
+                .rev() // reversed to allow popping later.
+                .collect::<Stack>()
+        })
+        .collect()
+}
+
+fn part_one(initial_stack: &[Stack], moves: &[Move]) -> String {
+    let mut stacks = transpose(initial_stack);
+    for step in moves {
+        for _ in 0..step.count {
+            if let Some(value) = stacks[step.source].pop() {
+                stacks[step.destination].push(value);
+            }
+        }
+    }
+
+    stacks
+        .iter_mut()
+        .map(|stack| stack.pop().unwrap())
+        .collect()
+}
+
+fn part_two(initial_stack: &[Stack], moves: &[Move]) -> String {
+    let mut stacks = transpose(initial_stack);
+
+    for step in moves {
+        let mut tmp: Vec<_> = Vec::new();
+
+        for _ in 0..step.count {
+            if let Some(value) = stacks[step.source].pop() {
+                tmp.push(value);
+            }
+        }
+        for _ in 0..tmp.len() {
+            let value = tmp.pop().unwrap();
+            stacks[step.destination].push(value);
+        }
+    }
+
+    stacks
+        .iter_mut()
+        .map(|stack| stack.pop().unwrap())
+        .collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let (initial_stack, moves) = parse_input(input);
+        Ok(part_one(&initial_stack, &moves))
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let (initial_stack, moves) = parse_input(input);
+        Ok(part_two(&initial_stack, &moves))
+    }
+}
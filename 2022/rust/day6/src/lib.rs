@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+
+fn find_unique_window(size: usize, input: &str) -> Option<(usize, String)> {
+    let mut begin = 0;
+    let mut end = size;
+    let chars: Vec<_> = input.chars().collect();
+    while end <= input.len() {
+        let mut seen = 0;
+        let mut i = end;
+        while i > begin {
+            i -= 1;
+            let ord = chars[i] as i32 - 'a' as i32;
+            if seen & 1 << ord != 0 {
+                end = end + (size - (end - i)) + 1;
+                begin = i + 1;
+                assert_eq!(end - begin, size);
+                break;
+            }
+            seen |= 1 << ord;
+        }
+        if i == begin {
+            return Some((end, input[begin..end].to_string()));
+        }
+    }
+
+    None
+}
+
+fn fast_hash_find_unique_window(size: usize, input: &str) -> Option<(usize, String)> {
+    let mut begin = 0;
+    let mut end = size;
+    let chars: Vec<_> = input.chars().collect();
+    while end <= input.len() {
+        let mut i = end;
+        let mut seen: HashSet<char> = HashSet::with_capacity(size);
+        while i > begin {
+            i -= 1;
+
+            if !seen.insert(chars[i]) {
+                end = end + (size - (end - i)) + 1;
+                begin = i + 1;
+                assert_eq!(end - begin, size);
+                break;
+            }
+        }
+        if i == begin {
+            return Some((end, input[begin..end].to_string()));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+fn hash_find_unique_window(size: usize, input: &str) -> Option<(usize, String)> {
+    for i in 0..=input.len() {
+        let window = &input[i..i+size];
+        let unique_chars: HashSet<char> = window.chars().collect();
+        if unique_chars.len() == size {
+            return Some((i + size, window.into()));
+        }
+    }
+    None
+}
+
+fn start_of_marker(size: usize, input: &str) -> Result<String> {
+    let input = input.trim();
+    let result = find_unique_window(size, input);
+    assert_eq!(fast_hash_find_unique_window(size, input), result);
+
+    result
+        .map(|(pos, _)| pos.to_string())
+        .ok_or_else(|| anyhow!("No solution found"))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    fn part1(&self, input: &str) -> Result<String> {
+        start_of_marker(4, input)
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        start_of_marker(14, input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_find_at_beginning() {
+        assert_eq!(hash_find_unique_window(4, "abcde"), Some((4, "abcd".into())));
+    }
+
+    #[test]
+    fn can_find_at_end() {
+        assert_eq!(hash_find_unique_window(4, "bbcde"), Some((5, "bcde".into())));
+    }
+}
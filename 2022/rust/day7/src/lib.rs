@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+
+fn part1(fs_size: &HashMap<String, u64>) -> u64 {
+    fs_size
+        .iter()
+        .filter_map(|(_, &value)| (value <= 100_000).then_some(value))
+        .sum()
+}
+
+fn part2(fs_size: &HashMap<String, u64>) -> Option<u64> {
+    let total_storage_size = 70_000_000;
+    let required_space = 30_000_000;
+    let used_storage: u64 = *fs_size.get("/").expect("root");
+    let remaining = total_storage_size - used_storage;
+    let missing_free_space = required_space - remaining;
+    fs_size
+        .values()
+        .filter(|&&value| value >= missing_free_space)
+        .min()
+        .copied()
+}
+
+fn directory_sizes(input: &str) -> HashMap<String, u64> {
+    let mut lines: Vec<_> = input.lines().rev().collect();
+    let mut fs_size: HashMap<String, u64> = HashMap::new();
+    let mut path: Vec<_> = Vec::new();
+    while let Some(line) = lines.pop() {
+        let parts: Vec<_> = line.split(' ').collect();
+        if parts[0] == "$" {
+            if parts[1] == "cd" {
+                if parts[2] == ".." {
+                    path.pop();
+                } else {
+                    path.push(parts[2]);
+                }
+            }
+        } else if parts[0] == "dir" {
+            // ???
+        } else {
+            let size: u64 = parts[0].parse().expect("a number");
+            for i in 0..path.len() {
+                let entry = fs_size.entry(path[0..i + 1].join("/")).or_insert(0);
+                *entry += size;
+            }
+        }
+    }
+
+    fs_size
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(&directory_sizes(input)).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        part2(&directory_sizes(input))
+            .map(|size| size.to_string())
+            .ok_or_else(|| anyhow!("no directory is large enough"))
+    }
+}
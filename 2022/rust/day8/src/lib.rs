@@ -0,0 +1,219 @@
+use std::cmp::max;
+use std::collections::HashMap;
+
+use advent_support::{Grid, Solution};
+use anyhow::Result;
+
+fn go_left(values: &[u8]) -> Vec<(usize, u8)> {
+    values.iter().copied().enumerate().rev().collect()
+}
+
+fn go_right(values: &[u8]) -> Vec<(usize, u8)> {
+    values.iter().copied().enumerate().collect()
+}
+
+fn next_greater(action: fn(&[u8]) -> Vec<(usize, u8)>, values: &[u8]) -> Vec<Option<u8>> {
+    let mut result = vec![None; values.len()];
+    let mut stack = Vec::new();
+
+    for (i, num) in action(values) {
+        while let Some(&idx) = stack.last() {
+            if values[idx] > num {
+                break;
+            }
+            let index = stack.pop().unwrap();
+            result[index] = Some(num);
+        }
+        stack.push(i);
+    }
+
+    result
+}
+
+fn next_greater_left(values: &[u8]) -> Vec<Option<u8>> {
+    next_greater(go_left, values)
+}
+
+fn next_greater_right(values: &[u8]) -> Vec<Option<u8>> {
+    next_greater(go_right, values)
+}
+
+fn modified_next_greater(
+    action: fn(&[u8]) -> Vec<(usize, u8)>,
+    values: &[u8],
+) -> Vec<Option<usize>> {
+    let mut result = vec![None; values.len()];
+    let mut stack = Vec::new();
+
+    for (i, num) in action(values) {
+        while let Some(&idx) = stack.last() {
+            if values[idx] > num {
+                break;
+            }
+            let index = stack.pop().unwrap();
+            result[index] = Some(i);
+        }
+        stack.push(i);
+    }
+
+    result
+}
+
+fn modified_next_greater_left(values: &[u8]) -> Vec<Option<usize>> {
+    modified_next_greater(go_left, values)
+}
+
+fn modified_next_greater_right(values: &[u8]) -> Vec<Option<usize>> {
+    modified_next_greater(go_right, values)
+}
+
+/// Apply `action` to every row of `forest` and to every column, returning the results in
+/// forest order.
+fn along_rows_and_columns<T: Clone>(
+    action: fn(&[u8]) -> Vec<T>,
+    forest: &Grid<u8>,
+) -> Result<(Grid<T>, Grid<T>)> {
+    let rows = Grid::from_rows(forest.rows().map(action))?;
+    let columns = Grid::from_rows(forest.transpose().rows().map(action))?.transpose();
+    Ok((rows, columns))
+}
+
+fn part1(forest: &Grid<u8>) -> Result<usize> {
+    let (blocking_tree_left, blocking_tree_above) =
+        along_rows_and_columns(next_greater_left, forest)?;
+    let (blocking_tree_right, blocking_tree_below) =
+        along_rows_and_columns(next_greater_right, forest)?;
+
+    let mut visible_trees: HashMap<(usize, usize), u8> = HashMap::new();
+    for (position, &tree) in forest.positions() {
+        if blocking_tree_left[position].is_none()
+            || blocking_tree_right[position].is_none()
+            || blocking_tree_above[position].is_none()
+            || blocking_tree_below[position].is_none()
+        {
+            visible_trees.entry(position).or_insert(tree);
+        }
+    }
+
+    Ok(visible_trees.len())
+}
+
+fn part1_brute(forest: &Grid<u8>) -> usize {
+    let mut visible_trees: HashMap<(usize, usize), u8> = HashMap::new();
+    for ((j, i), &tree) in forest.positions() {
+        let row = forest.row(i);
+        let is_visible = |&value: &u8| value < tree;
+        if (row[0..j]).iter().all(is_visible)
+            || row[j + 1..].iter().all(is_visible)
+            || forest.column(j).take(i).all(is_visible)
+            || forest.column(j).skip(i + 1).all(is_visible)
+        {
+            visible_trees.entry((j, i)).or_insert(tree);
+        }
+    }
+
+    visible_trees.len()
+}
+
+fn part2(forest: &Grid<u8>) -> Result<usize> {
+    let w = forest.width();
+    let h = forest.height();
+
+    let (blocking_trees_left, blocking_trees_above) =
+        along_rows_and_columns(modified_next_greater_left, forest)?;
+    let (blocking_trees_right, blocking_trees_below) =
+        along_rows_and_columns(modified_next_greater_right, forest)?;
+
+    let mut highest_dist = 0;
+    for ((j, i), _) in forest.positions() {
+        let position = (j, i);
+        let left_dist = blocking_trees_left[position].map_or(j, |distance| j - distance);
+        let right_dist = blocking_trees_right[position].map_or(w - j - 1, |distance| distance - j);
+        let above_dist = blocking_trees_above[position].map_or(i, |distance| i - distance);
+        let below_dist = blocking_trees_below[position].map_or(h - i - 1, |distance| distance - i);
+
+        let viewing_dist = right_dist * left_dist * above_dist * below_dist;
+        highest_dist = max(highest_dist, viewing_dist);
+    }
+
+    Ok(highest_dist)
+}
+
+fn scenic_score<I>(values: I, sentinel: u8) -> usize
+where
+    I: Iterator<Item = u8> + ExactSizeIterator,
+{
+    let mut count = 0;
+    for value in values {
+        count += 1;
+        if value >= sentinel {
+            break;
+        }
+    }
+    count
+}
+
+fn part2_brute(forest: &Grid<u8>) -> usize {
+    let mut max_scenic_score = 0;
+    for ((j, i), &tree) in forest.positions() {
+        let row = forest.row(i);
+        let score = scenic_score(row[0..j].iter().rev().copied(), tree)
+            * scenic_score(row[j + 1..].iter().copied(), tree)
+            * scenic_score(forest.column(j).take(i).rev().copied(), tree)
+            * scenic_score(forest.column(j).skip(i + 1).copied(), tree);
+        max_scenic_score = max(max_scenic_score, score);
+    }
+
+    max_scenic_score
+}
+
+fn parse_forest(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |c| Ok(c as u8 - b'0'))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let forest = parse_forest(input)?;
+        let visible = part1(&forest)?;
+        debug_assert_eq!(visible, part1_brute(&forest));
+        Ok(visible.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let forest = parse_forest(input)?;
+        let score = part2(&forest)?;
+        debug_assert_eq!(score, part2_brute(&forest));
+        Ok(score.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<u8> {
+        parse_forest("30373\n25512\n65332\n33549\n35390\n").unwrap()
+    }
+
+    #[test]
+    fn test_visible() {
+        let data = example();
+
+        assert_eq!(part1(&data).unwrap(), 21);
+        assert_eq!(part1_brute(&data), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = example();
+
+        assert_eq!(scenic_score(data.column(2).take(3).rev().copied(), 5), 2);
+        assert_eq!(scenic_score(data.row(3)[0..2].iter().rev().copied(), 5), 2);
+        assert_eq!(scenic_score(data.column(2).skip(4).copied(), 5), 1);
+        assert_eq!(scenic_score(data.row(3)[3..].iter().copied(), 5), 2);
+        assert_eq!(part2_brute(&data), 8);
+        assert_eq!(part2(&data).unwrap(), 8);
+    }
+}
@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::io::Write;
+
+use advent_support::{Solution, TerminalAnimation};
+use anyhow::Result;
+
+/// Columns and rows of the area around the head drawn by [`animate`].
+const VIEW_WIDTH: i64 = 61;
+const VIEW_HEIGHT: i64 = 21;
+
+fn signum(value: i64) -> i64 {
+    match value.cmp(&0) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl Position {
+    fn follow(&self, other: &Position) -> Option<Position> {
+        let delta_x = other.x - self.x;
+        let delta_y = other.y - self.y;
+
+        if delta_x.abs() > 1 || delta_y.abs() > 1 {
+            Some(Position {
+                x: self.x + signum(delta_x),
+                y: self.y + signum(delta_y),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn part1(input: &[&str]) -> usize {
+    solve(input, 2)
+}
+
+fn part2(input: &[&str]) -> usize {
+    solve(input, 10)
+}
+
+fn solve(input: &[&str], num_knots: usize) -> usize {
+    simulate(input, num_knots, |_, _| Ok(())).expect("nothing to fail")
+}
+
+/// Move the rope, calling `observe` with the knots and every position the tail has visited
+/// after each step.
+fn simulate(
+    input: &[&str],
+    num_knots: usize,
+    mut observe: impl FnMut(&[Position], &HashSet<Position>) -> Result<()>,
+) -> Result<usize> {
+    let mut tail_positions: HashSet<Position> = HashSet::new();
+
+    let mut knots = vec![Position::default(); num_knots];
+    tail_positions.insert(knots[num_knots - 1]);
+
+    for line in input {
+        let (direction, count) = line.split_once(' ').expect("direction and count");
+        let count = count.parse::<i64>().expect("number");
+
+        for _ in 0..count {
+            match direction {
+                "U" => {
+                    knots[0].y += 1;
+                }
+                "R" => {
+                    knots[0].x += 1;
+                }
+                "D" => {
+                    knots[0].y -= 1;
+                }
+                "L" => {
+                    knots[0].x -= 1;
+                }
+                _ => {
+                    unreachable!();
+                }
+            }
+
+            for i in 1..knots.len() {
+                if let Some(new_pos) = knots[i].follow(&knots[i - 1]) {
+                    knots[i] = new_pos;
+                }
+            }
+            tail_positions.insert(knots[num_knots - 1]);
+            observe(&knots, &tail_positions)?;
+        }
+    }
+
+    Ok(tail_positions.len())
+}
+
+/// The `width` by `height` area centered on the head, drawn like the puzzle: the head as `H`,
+/// other knots by number, the start as `s` and the tail's trail as `#`.
+fn view(knots: &[Position], visited: &HashSet<Position>, width: i64, height: i64) -> String {
+    let top = knots[0].y + height / 2;
+    let left = knots[0].x - width / 2;
+
+    let mut picture = String::new();
+    for y in (top - height + 1..=top).rev() {
+        for x in left..left + width {
+            let position = Position { x, y };
+            let symbol = match knots.iter().position(|knot| *knot == position) {
+                Some(0) => 'H',
+                Some(index) => char::from_digit(index as u32 % 10, 10).unwrap(),
+                None if position == Position::default() => 's',
+                None if visited.contains(&position) => '#',
+                None => '.',
+            };
+            picture.push(symbol);
+        }
+        picture.push('\n');
+    }
+    picture
+}
+
+/// Redraw the rope in the terminal after every step, keeping the head in the middle.
+pub fn animate<W: Write>(
+    input: &str,
+    num_knots: usize,
+    animation: &mut TerminalAnimation<W>,
+) -> Result<()> {
+    let input: Vec<&str> = input.lines().collect();
+    let mut last = String::new();
+    let visited = simulate(&input, num_knots, |knots, visited| {
+        last = view(knots, visited, VIEW_WIDTH, VIEW_HEIGHT);
+        animation.frame(&last)
+    })?;
+    animation.frame(&format!("{}{} positions visited", last, visited))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let input: Vec<&str> = input.lines().collect();
+        Ok(part1(&input).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let input: Vec<&str> = input.lines().collect();
+        Ok(part2(&input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let data = vec!["R 4", "U 4", "L 3", "D 1", "R 4", "D 1", "L 5", "R 2"];
+
+        assert_eq!(part1(&data), 13);
+    }
+
+    #[test]
+    fn test_example2() {
+        let data = vec!["R 5", "U 8", "L 8", "D 3", "R 17", "D 10", "L 25", "U 20"];
+
+        assert_eq!(part2(&data), 36);
+    }
+
+    #[test]
+    fn test_view() {
+        let data = vec!["R 4", "U 4", "L 3", "D 1", "R 4", "D 1", "L 5", "R 2"];
+        let mut last = String::new();
+        simulate(&data, 10, |knots, visited| {
+            last = view(knots, visited, 7, 5);
+            Ok(())
+        })
+        .unwrap();
+        // The final state from the puzzle, with the knots behind others hidden.
+        assert_eq!(last, ".......\n.......\n..1H3..\n..5....\n.6.....\n");
+    }
+}
@@ -0,0 +1,14 @@
+use advent_support::Solution;
+use anyhow::Result;
+
+pub struct Day;
+
+impl Solution for Day {
+    fn part1(&self, input: &str) -> Result<String> {
+        ...
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        ...
+    }
+}
@@ -1,15 +1,5 @@
-use std::fs;
+use anyhow::Result;
 
-fn part1(...) {
-}
-
-fn part2(...) {
-}
-
-fn main() {
-    let input_raw = fs::read_to_string("../inputs/7").expect("data");
-    ...
-
-    part1();
-    part2();
+fn main() -> Result<()> {
+    advent_support::run(&aoc2022_{{crate_name}}::Day)
 }
@@ -0,0 +1,139 @@
+use advent_support::Solution;
+use anyhow::{anyhow, Result};
+use itertools::multiunzip;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{space0, space1, u32};
+use nom::multi::separated_list1;
+use nom::sequence::{separated_pair, terminated};
+use nom::IResult;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rgb {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+impl Rgb {
+    fn add(&self, other: &Rgb) -> Rgb {
+        Rgb {
+            red: self.red + other.red,
+            green: self.green + other.green,
+            blue: self.blue + other.blue,
+        }
+    }
+
+    fn tuple(&self) -> (u32, u32, u32) {
+        (self.red, self.green, self.blue)
+    }
+
+    fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+fn color(input: &str) -> IResult<&str, &str> {
+    alt((tag("red"), tag("green"), tag("blue")))(input)
+}
+
+fn single(input: &str) -> IResult<&str, Rgb> {
+    let (input, (number, name)) = separated_pair(u32, space1, color)(input)?;
+    let rgb = match name {
+        "red" => Rgb {
+            red: number,
+            green: 0,
+            blue: 0,
+        },
+        "green" => Rgb {
+            red: 0,
+            green: number,
+            blue: 0,
+        },
+        "blue" => Rgb {
+            red: 0,
+            green: 0,
+            blue: number,
+        },
+        _ => unimplemented!(),
+    };
+    Ok((input, rgb))
+}
+
+fn double(input: &str) -> IResult<&str, Rgb> {
+    let (input, (color1, color2)) =
+        separated_pair(single, terminated(tag(","), space0), single)(input)?;
+    Ok((input, color1.add(&color2)))
+}
+
+fn triple(input: &str) -> IResult<&str, Rgb> {
+    let (input, (color1, color2)) =
+        separated_pair(double, terminated(tag(","), space0), single)(input)?;
+    Ok((input, color1.add(&color2)))
+}
+
+fn parse_rgb_values(input: &str) -> IResult<&str, Rgb> {
+    alt((triple, double, single))(input)
+}
+
+// Game 1: 4 blue, 4 red, 16 green; 14 green, 5 red; 1 blue, 3 red, 5 green
+fn game(input: &str) -> IResult<&str, (u32, Vec<Rgb>)> {
+    let (input, ((_, number), values)) = separated_pair(
+        separated_pair(tag("Game"), space1, u32),
+        terminated(tag(":"), space0),
+        separated_list1(terminated(tag(";"), space0), parse_rgb_values),
+    )(input)?;
+    Ok((input, (number, values)))
+}
+
+fn valid_game(bag: &Rgb, game: &Rgb) -> bool {
+    game.red <= bag.red && game.green <= bag.green && game.blue <= bag.blue
+}
+
+fn minimal_bag(games: &[Rgb]) -> Rgb {
+    let (reds, greens, blues): (Vec<u32>, Vec<u32>, Vec<u32>) =
+        multiunzip(games.iter().map(|g| g.tuple()));
+    Rgb {
+        red: *reds.iter().max().unwrap(),
+        green: *greens.iter().max().unwrap(),
+        blue: *blues.iter().max().unwrap(),
+    }
+}
+
+fn parse_games(input: &str) -> Result<Vec<(u32, Vec<Rgb>)>> {
+    input
+        .lines()
+        .map(|line| {
+            game(line)
+                .map(|(_, g)| g)
+                .map_err(|e| anyhow!("{:?}", e))
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    fn part1(&self, input: &str) -> Result<String> {
+        let bag = Rgb {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let possible: u32 = parse_games(input)?
+            .iter()
+            .filter(|g| g.1.iter().all(|x| valid_game(&bag, x)))
+            .map(|g| g.0)
+            .sum();
+        Ok(possible.to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        let powers: u32 = parse_games(input)?
+            .iter()
+            .map(|g| minimal_bag(&g.1).power())
+            .sum();
+        Ok(powers.to_string())
+    }
+}
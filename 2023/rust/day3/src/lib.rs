@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use advent_support::{Grid, Solution};
+use anyhow::Result;
+
+fn is_symbol(c: u8) -> bool {
+    c != b'.' && !c.is_ascii_digit()
+}
+
+/// A part number and the row and columns it spans.
+struct Number {
+    value: u32,
+    y: usize,
+    begin: usize,
+    end: usize,
+}
+
+fn find_numbers(schematic: &Grid<u8>) -> Vec<Number> {
+    let mut numbers = Vec::new();
+
+    for (y, row) in schematic.rows().enumerate() {
+        let mut cursor = 0;
+        while cursor < row.len() {
+            let mut current = cursor;
+            while current < row.len() && row[current].is_ascii_digit() {
+                current += 1;
+            }
+            if current == cursor {
+                current += 1;
+            } else {
+                let value = std::str::from_utf8(&row[cursor..current])
+                    .expect("a string")
+                    .parse()
+                    .expect("a number");
+                numbers.push(Number {
+                    value,
+                    y,
+                    begin: cursor,
+                    end: current,
+                });
+            }
+            cursor = current;
+        }
+    }
+
+    numbers
+}
+
+fn find_adjacent_symbol(
+    specific: Option<u8>,
+    schematic: &Grid<u8>,
+    number: &Number,
+) -> Option<(usize, usize)> {
+    (number.begin..number.end)
+        .flat_map(|x| schematic.neighbors8(x, number.y))
+        .find(|&position| {
+            let c = schematic[position];
+            is_symbol(c) && specific.is_none_or(|symbol| symbol == c)
+        })
+}
+
+fn part1(schematic: &Grid<u8>) -> u32 {
+    find_numbers(schematic)
+        .iter()
+        .filter(|number| find_adjacent_symbol(None, schematic, number).is_some())
+        .map(|number| number.value)
+        .sum()
+}
+
+fn part2(schematic: &Grid<u8>) -> u32 {
+    let mut unpaired: HashMap<(usize, usize), u32> = HashMap::new();
+    let mut sum = 0;
+
+    for number in find_numbers(schematic) {
+        if let Some(position) = find_adjacent_symbol(Some(b'*'), schematic, &number) {
+            match unpaired.get(&position) {
+                Some(value) => {
+                    sum += value * number.value;
+                }
+                None => {
+                    unpaired.insert(position, number.value);
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+fn parse_schematic(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |c| Ok(c as u8))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    fn part1(&self, input: &str) -> Result<String> {
+        Ok(part1(&parse_schematic(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str) -> Result<String> {
+        Ok(part2(&parse_schematic(input)?).to_string())
+    }
+}
@@ -0,0 +1,355 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+/// A fighter's stats.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Combatant {
+    pub hit_points: u32,
+    pub damage: u32,
+    pub armor: u32,
+    /// Paid for moves, in fights that have a price on them.
+    pub mana: u32,
+}
+
+impl Combatant {
+    /// Read a fighter from lines like `Hit Points: 103`, the way the puzzles describe bosses.
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut combatant = Combatant::default();
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| anyhow!("expected `<stat>: <value>`, found {:?}", line))?;
+            let value = value
+                .trim()
+                .parse()
+                .with_context(|| format!("invalid value in {:?}", line))?;
+            match name.trim() {
+                "Hit Points" => combatant.hit_points = value,
+                "Damage" => combatant.damage = value,
+                "Armor" => combatant.armor = value,
+                "Mana" => combatant.mana = value,
+                stat => bail!("unknown stat {:?}", stat),
+            }
+        }
+        if !combatant.is_alive() {
+            bail!("no hit points");
+        }
+        Ok(combatant)
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.hit_points > 0
+    }
+
+    /// Damage done to `defender` by each attack. Armor never brings it below one.
+    pub fn hit(&self, defender: &Combatant) -> u32 {
+        self.damage.saturating_sub(defender.armor).max(1)
+    }
+
+    /// Attack `defender`, returning the damage done.
+    pub fn attack(&self, defender: &mut Combatant) -> u32 {
+        let damage = self.hit(defender);
+        defender.hit_points = defender.hit_points.saturating_sub(damage);
+        damage
+    }
+}
+
+/// Whose turn it is. The player always goes first.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Side {
+    Player,
+    Boss,
+}
+
+/// How the fight stands.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Continue,
+    /// The player's move could not be made.
+    Invalid,
+    Lose,
+    Win,
+}
+
+/// The parts of a turn, in the order they happen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Step {
+    /// Anything the fight does before effects, like draining the player in hard mode.
+    Upkeep,
+    Effects,
+    Move,
+}
+
+/// The rules of a fight.
+pub trait Combat {
+    /// Names an effect that lasts several turns, like a spell.
+    type Status: Clone + Ord;
+    /// What the player chooses to do on their turn.
+    type Move;
+
+    /// Happens at the start of every turn, before effects.
+    fn upkeep(&self, _side: Side, _battle: &mut Battle<Self::Status>) {}
+
+    /// Happens at the start of every turn while `status` is active.
+    fn each_turn(&self, _status: &Self::Status, _player: &mut Combatant, _boss: &mut Combatant) {}
+
+    /// Happens as `status` wears off.
+    fn on_expire(&self, _status: &Self::Status, _player: &mut Combatant, _boss: &mut Combatant) {}
+
+    /// Make the player's move, or return [`Verdict::Invalid`] without changing anything if it
+    /// cannot be made.
+    fn player_move(&self, choice: &Self::Move, battle: &mut Battle<Self::Status>) -> Verdict;
+
+    /// The boss simply attacks.
+    fn boss_move(&self, battle: &mut Battle<Self::Status>) -> Verdict {
+        let Battle { player, boss, .. } = battle;
+        boss.attack(player);
+        Verdict::Continue
+    }
+}
+
+/// Called after each step of a turn with the battle before and after it.
+pub type Watch<'a, S> = dyn FnMut(Side, Step, &Battle<S>, &Battle<S>) + 'a;
+
+/// A fight in progress.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Battle<S> {
+    pub player: Combatant,
+    pub boss: Combatant,
+    /// Turns left for each active effect.
+    pub effects: BTreeMap<S, u32>,
+    /// Paid for the player's moves so far.
+    pub mana_spent: u32,
+}
+
+impl<S: Clone + Ord> Battle<S> {
+    pub fn new(player: Combatant, boss: Combatant) -> Self {
+        Self {
+            player,
+            boss,
+            effects: BTreeMap::new(),
+            mana_spent: 0,
+        }
+    }
+
+    /// Start `status` for `turns` turns, returning `false` if it is already active.
+    pub fn start_effect(&mut self, status: S, turns: u32) -> bool {
+        if self.effects.contains_key(&status) {
+            return false;
+        }
+        self.effects.insert(status, turns);
+        true
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        if !self.boss.is_alive() {
+            Verdict::Win
+        } else if !self.player.is_alive() {
+            Verdict::Lose
+        } else {
+            Verdict::Continue
+        }
+    }
+
+    /// Play the player's turn and then the boss's, stopping as soon as the fight is decided or
+    /// the player's move turns out to be invalid.
+    pub fn round<C>(&mut self, combat: &C, choice: &C::Move) -> Verdict
+    where
+        C: Combat<Status = S>,
+    {
+        self.play_round(combat, choice, None)
+    }
+
+    pub fn round_watched<C>(
+        &mut self,
+        combat: &C,
+        choice: &C::Move,
+        watch: &mut Watch<S>,
+    ) -> Verdict
+    where
+        C: Combat<Status = S>,
+    {
+        self.play_round(combat, choice, Some(watch))
+    }
+
+    fn play_round<C>(
+        &mut self,
+        combat: &C,
+        choice: &C::Move,
+        mut watch: Option<&mut Watch<S>>,
+    ) -> Verdict
+    where
+        C: Combat<Status = S>,
+    {
+        for side in [Side::Player, Side::Boss].iter().copied() {
+            for step in [Step::Upkeep, Step::Effects, Step::Move].iter().copied() {
+                let before = watch.is_some().then(|| self.clone());
+                let decision = match (step, side) {
+                    (Step::Upkeep, _) => {
+                        combat.upkeep(side, self);
+                        Verdict::Continue
+                    }
+                    (Step::Effects, _) => {
+                        self.resolve_effects(combat);
+                        Verdict::Continue
+                    }
+                    (Step::Move, Side::Player) => combat.player_move(choice, self),
+                    (Step::Move, Side::Boss) => combat.boss_move(self),
+                };
+                if let (Some(watch), Some(before)) = (watch.as_mut(), before) {
+                    watch(side, step, &before, self);
+                }
+
+                let verdict = match decision {
+                    Verdict::Continue => self.verdict(),
+                    decision => decision,
+                };
+                if verdict != Verdict::Continue {
+                    return verdict;
+                }
+            }
+        }
+        Verdict::Continue
+    }
+
+    fn resolve_effects<C>(&mut self, combat: &C)
+    where
+        C: Combat<Status = S>,
+    {
+        let Battle {
+            player,
+            boss,
+            effects,
+            ..
+        } = self;
+        for (status, turns) in effects.iter_mut() {
+            *turns = turns.saturating_sub(1);
+            combat.each_turn(status, player, boss);
+            if *turns == 0 {
+                combat.on_expire(status, player, boss);
+            }
+        }
+        effects.retain(|_, turns| *turns > 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fists, plus a poison that costs 10 mana and does 3 damage a turn for 3 turns.
+    struct Brawl;
+
+    #[derive(Debug, PartialEq)]
+    enum Choice {
+        Punch,
+        Poison,
+    }
+
+    impl Combat for Brawl {
+        type Status = &'static str;
+        type Move = Choice;
+
+        fn each_turn(&self, _status: &&'static str, _player: &mut Combatant, boss: &mut Combatant) {
+            boss.hit_points = boss.hit_points.saturating_sub(3);
+        }
+
+        fn player_move(&self, choice: &Choice, battle: &mut Battle<&'static str>) -> Verdict {
+            match choice {
+                Choice::Punch => {
+                    let Battle { player, boss, .. } = battle;
+                    player.attack(boss);
+                }
+                Choice::Poison => {
+                    if battle.player.mana < 10 || !battle.start_effect("poison", 3) {
+                        return Verdict::Invalid;
+                    }
+                    battle.player.mana -= 10;
+                    battle.mana_spent += 10;
+                }
+            }
+            Verdict::Continue
+        }
+    }
+
+    fn fighter(hit_points: u32, damage: u32, armor: u32) -> Combatant {
+        Combatant {
+            hit_points,
+            damage,
+            armor,
+            mana: 0,
+        }
+    }
+
+    #[test]
+    fn test_attack() {
+        let mut defender = fighter(10, 0, 5);
+        assert_eq!(fighter(1, 8, 0).attack(&mut defender), 3);
+        // Armor never stops the last point of damage.
+        assert_eq!(fighter(1, 2, 0).attack(&mut defender), 1);
+        assert_eq!(defender.hit_points, 6);
+        assert_eq!(fighter(1, 100, 0).attack(&mut defender), 95);
+        assert!(!defender.is_alive());
+    }
+
+    #[test]
+    fn test_round() {
+        // The player strikes first, so wins a race they would tie.
+        let mut battle: Battle<&str> = Battle::new(fighter(2, 1, 0), fighter(2, 1, 0));
+        assert_eq!(battle.round(&Brawl, &Choice::Punch), Verdict::Continue);
+        assert_eq!(battle.round(&Brawl, &Choice::Punch), Verdict::Win);
+        assert_eq!(battle.player.hit_points, 1);
+
+        let mut battle = Battle::new(fighter(1, 1, 0), fighter(10, 1, 0));
+        assert_eq!(battle.round(&Brawl, &Choice::Punch), Verdict::Lose);
+    }
+
+    #[test]
+    fn test_effects() {
+        let player = Combatant {
+            mana: 10,
+            ..fighter(10, 1, 0)
+        };
+        let mut battle = Battle::new(player, fighter(30, 1, 0));
+        let mut steps = Vec::new();
+        let verdict =
+            battle.round_watched(&Brawl, &Choice::Poison, &mut |side, step, before, after| {
+                steps.push((side, step, before.boss.hit_points, after.boss.hit_points))
+            });
+        assert_eq!(verdict, Verdict::Continue);
+        // The poison starts working on the boss's turn.
+        assert_eq!(
+            steps,
+            [
+                (Side::Player, Step::Upkeep, 30, 30),
+                (Side::Player, Step::Effects, 30, 30),
+                (Side::Player, Step::Move, 30, 30),
+                (Side::Boss, Step::Upkeep, 30, 30),
+                (Side::Boss, Step::Effects, 30, 27),
+                (Side::Boss, Step::Move, 27, 27),
+            ]
+        );
+        assert_eq!(battle.mana_spent, 10);
+
+        // Still active, and unaffordable anyway, so the round stops at the player's move.
+        let before = battle.clone();
+        assert_eq!(battle.round(&Brawl, &Choice::Poison), Verdict::Invalid);
+        assert_eq!(battle.boss.hit_points, before.boss.hit_points - 3);
+
+        assert_eq!(battle.round(&Brawl, &Choice::Punch), Verdict::Continue);
+        assert!(battle.effects.is_empty());
+        assert_eq!(battle.boss.hit_points, 30 - 3 * 3 - 1);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Combatant::parse("Hit Points: 103\nDamage: 9\nArmor: 2\n").unwrap(),
+            fighter(103, 9, 2)
+        );
+        assert!(Combatant::parse("Hit Points: 12\nSpeed: 3\n").is_err());
+        assert!(Combatant::parse("Damage: 3\n").is_err());
+        assert!(Combatant::parse("Hit Points: twelve\n").is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use anyhow::{bail, Result};
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A fixed size 2D grid stored in row-major order and indexed by `(x, y)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Shift row `y` right by `count`, wrapping around.
+    pub fn rotate_row(&mut self, y: usize, count: usize) {
+        if self.width == 0 {
+            return;
+        }
+        let start = y * self.width;
+        self.cells[start..start + self.width].rotate_right(count % self.width);
+    }
+
+    /// Shift column `x` down by `count`, wrapping around.
+    pub fn rotate_column(&mut self, x: usize, count: usize) {
+        let column: Vec<T> = self.column(x).cloned().collect();
+        for (y, value) in column.into_iter().enumerate() {
+            let new_y = (y + count) % self.height;
+            self[(x, new_y)] = value;
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from rows, which must all be the same length.
+    pub fn from_rows<I, R>(rows: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    bail!("row {} has {} cells, expected {}", height, row_width, width)
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    /// Build a grid from text, one row per line, converting each character with `convert`.
+    pub fn parse<F>(input: &str, mut convert: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&mut convert).collect::<Result<Vec<T>>>())
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[self.offset(x, y)])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            let offset = self.offset(x, y);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// Index the grid as if it repeated forever in every direction. An empty grid repeated is
+    /// still empty, so that is the only time this returns `None`.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> Option<&T> {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        Some(&self[(x, y)])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        let start = y * self.width;
+        &self.cells[start..start + self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        let start = y * self.width;
+        &mut self.cells[start..start + self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} out of range", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every cell along with its `(x, y)` position, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, value)| ((offset % width, offset / width), value))
+    }
+
+    /// The up to four positions directly above, below, left and right of `(x, y)`.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &ORTHOGONAL)
+    }
+
+    /// The up to eight positions surrounding `(x, y)`, including diagonals.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &ALL_DIRECTIONS)
+    }
+
+    fn neighbors(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            self.contains(x, y).then_some((x, y))
+        })
+    }
+
+    fn offset(&self, x: usize, y: usize) -> usize {
+        x + (y * self.width)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(self.contains(x, y), "({}, {}) out of range", x, y);
+        &self.cells[self.offset(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(self.contains(x, y), "({}, {}) out of range", x, y);
+        let offset = self.offset(x, y);
+        &mut self.cells[offset]
+    }
+}
+
+/// One line per row, without a trailing newline.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(width: usize, height: usize) -> Grid<usize> {
+        Grid::from_rows((0..height).map(|y| (0..width).map(move |x| x + (y * 10)))).unwrap()
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = numbered(3, 2);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 1)], 12);
+        assert_eq!(grid.get(3, 0), None);
+
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("#.\n.#\n", |c| Ok(c == '#')).unwrap();
+        assert_eq!(grid.iter().filter(|lit| **lit).count(), 2);
+        assert!(grid[(1, 1)]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = numbered(3, 2);
+        assert_eq!(grid.row(1), &[10, 11, 12]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![1, 11]);
+        assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), vec![12, 2]);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = numbered(3, 2);
+        let transposed = grid.transpose();
+        assert_eq!(transposed.width(), 2);
+        assert_eq!(transposed.height(), 3);
+        assert_eq!(transposed.row(2), &[2, 12]);
+        assert_eq!(transposed.transpose(), grid);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut grid = numbered(3, 2);
+        grid.rotate_row(0, 4);
+        assert_eq!(grid.row(0), &[2, 0, 1]);
+        grid.rotate_column(0, 1);
+        assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), vec![10, 2]);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = numbered(3, 2);
+        assert_eq!(grid.get_wrapping(4, 0), Some(&1));
+        assert_eq!(grid.get_wrapping(-1, -1), Some(&12));
+
+        assert_eq!(Grid::new(0, 2, 0).get_wrapping(1, 1), None);
+        assert_eq!(Grid::new(3, 0, 0).get_wrapping(1, 1), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = numbered(3, 3);
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(grid.neighbors8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::parse("ab\ncd", Ok).unwrap();
+        assert_eq!(grid.to_string(), "ab\ncd");
+    }
+}
@@ -0,0 +1,64 @@
+use std::error::Error;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use anyhow::Result;
+
+mod combat;
+mod grid;
+mod machine;
+mod ocr;
+mod parse;
+mod render;
+mod search;
+mod solution;
+mod terminal;
+
+pub use combat::{Battle, Combat, Combatant, Side, Step, Verdict, Watch};
+pub use grid::Grid;
+pub use machine::{
+    Flow, Instruction, Machine, MachineError, Observer, Registers, Stop, TraceEntry,
+};
+pub use ocr::read_letters;
+pub use parse::{parse_each_line, ParseError, Span};
+pub use render::{gray, Animation, Image};
+pub use search::{a_star, breadth_first, dijkstra, Found, KeepAll, Problem, Prune, Visited};
+pub use solution::{print_answer, run, Solution};
+pub use terminal::{animation_args, TerminalAnimation};
+
+pub fn read_input<T: FromStr>() -> Result<Vec<T>>
+where
+    <T as FromStr>::Err: Error + Send + Sync + 'static,
+{
+    let stdin = io::stdin();
+    let handle = stdin.lock();
+
+    let mut results = Vec::<T>::new();
+    for line in handle.lines() {
+        let line = line?;
+        let value = line.parse::<T>()?;
+        results.push(value);
+    }
+
+    Ok(results)
+}
+
+/// Read all of stdin as a single string, one puzzle line per line.
+pub fn read_input_string() -> Result<String> {
+    let mut input = read_input::<String>()?.join("\n");
+    input.push('\n');
+    Ok(input)
+}
+
+pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>>
+where
+    <T as FromStr>::Err: Error + Send + Sync + 'static,
+{
+    let mut results = Vec::<T>::new();
+    for line in input.lines() {
+        let value = line.parse::<T>()?;
+        results.push(value);
+    }
+
+    Ok(results)
+}
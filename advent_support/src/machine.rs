@@ -0,0 +1,475 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::error::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A fixed set of named integer registers, all starting at zero.
+///
+/// Instructions normally resolve names to indices once, while parsing, and then use indexing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Registers {
+    names: Vec<String>,
+    values: Vec<i64>,
+}
+
+impl Registers {
+    pub fn new(names: &[&str]) -> Self {
+        Self {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            values: vec![0; names.len()],
+        }
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<i64> {
+        self.index_of(name).map(|index| self.values[index])
+    }
+
+    /// Set register `name`, returning `false` if there is no such register.
+    pub fn set(&mut self, name: &str, value: i64) -> bool {
+        match self.index_of(name) {
+            Some(index) => {
+                self.values[index] = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn name(&self, index: usize) -> &str {
+        &self.names[index]
+    }
+
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+impl Index<usize> for Registers {
+    type Output = i64;
+
+    fn index(&self, index: usize) -> &i64 {
+        &self.values[index]
+    }
+}
+
+impl IndexMut<usize> for Registers {
+    fn index_mut(&mut self, index: usize) -> &mut i64 {
+        &mut self.values[index]
+    }
+}
+
+/// Space separated `name=value` pairs.
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (name, value)) in self.names.iter().zip(&self.values).enumerate() {
+            if index > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Where execution continues after an instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Flow {
+    Next,
+    /// Move the program counter by this many instructions.
+    Jump(i64),
+}
+
+/// One instruction of a machine's instruction set.
+pub trait Instruction: fmt::Debug {
+    /// How many cycles the instruction takes. Its effects land once they have all passed.
+    fn cycles(&self) -> usize {
+        1
+    }
+
+    fn execute(&self, registers: &mut Registers) -> Flow;
+}
+
+/// Watches a running machine.
+pub trait Observer {
+    /// Called during each cycle, before the instruction being executed has taken effect.
+    fn on_cycle(&mut self, cycle: usize, registers: &Registers);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MachineError {
+    /// A jump landed before the first instruction.
+    JumpOutOfRange { pc: usize, offset: i64 },
+    /// The machine was still running after the limit set by [`Machine::set_step_limit`].
+    StepLimit { steps: usize },
+}
+
+impl fmt::Display for MachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MachineError::JumpOutOfRange { pc, offset } => write!(
+                f,
+                "Invalid offset for jump at index {}, offset {}",
+                pc, offset
+            ),
+            MachineError::StepLimit { steps } => {
+                write!(f, "Still running after {} steps", steps)
+            }
+        }
+    }
+}
+
+impl Error for MachineError {}
+
+/// Why a run returned without an error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The program counter moved past the last instruction.
+    Halted,
+    /// The next instruction to run is at a breakpoint.
+    Breakpoint(usize),
+    /// The requested number of steps ran.
+    Paused,
+}
+
+/// One executed instruction, as recorded by [`Machine::enable_trace`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// Steps executed before this one.
+    pub step: usize,
+    pub pc: usize,
+    pub before: Vec<i64>,
+    pub after: Vec<i64>,
+}
+
+/// Runs a program until the program counter moves past its last instruction.
+#[derive(Debug)]
+pub struct Machine<I> {
+    program: Vec<I>,
+    registers: Registers,
+    pc: usize,
+    cycle: usize,
+    steps: usize,
+    step_limit: Option<usize>,
+    breakpoints: BTreeSet<usize>,
+    trace: Option<(usize, VecDeque<TraceEntry>)>,
+}
+
+impl<I: Instruction> Machine<I> {
+    pub fn new(program: Vec<I>, registers: Registers) -> Self {
+        Self {
+            program,
+            registers,
+            pc: 0,
+            cycle: 0,
+            steps: 0,
+            step_limit: None,
+            breakpoints: BTreeSet::new(),
+            trace: None,
+        }
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut Registers {
+        &mut self.registers
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// The number of cycles completed so far.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    /// The number of instructions executed so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn program(&self) -> &[I] {
+        &self.program
+    }
+
+    /// The instruction that will run next, or `None` once halted.
+    pub fn current(&self) -> Option<&I> {
+        self.program.get(self.pc)
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Fail with [`MachineError::StepLimit`] instead of executing more than `steps` instructions.
+    pub fn set_step_limit(&mut self, steps: usize) {
+        self.step_limit = Some(steps);
+    }
+
+    /// Stop running just before the instruction at `pc` executes. A run always executes at least
+    /// one instruction, so running again continues past the breakpoint.
+    pub fn add_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.insert(pc);
+    }
+
+    pub fn remove_breakpoint(&mut self, pc: usize) {
+        self.breakpoints.remove(&pc);
+    }
+
+    /// Record the most recent `keep` steps.
+    pub fn enable_trace(&mut self, keep: usize) {
+        self.trace = Some((keep, VecDeque::with_capacity(keep)));
+    }
+
+    /// The recorded steps, oldest first.
+    pub fn trace(&self) -> impl Iterator<Item = &TraceEntry> {
+        self.trace.iter().flat_map(|(_, entries)| entries)
+    }
+
+    /// The recorded steps, one per line, as `step pc instruction before -> after`.
+    pub fn dump_trace(&self) -> String {
+        let registers = |values: &[i64]| {
+            let mut registers = self.registers.clone();
+            registers.values.copy_from_slice(values);
+            registers.to_string()
+        };
+        self.trace()
+            .map(|entry| {
+                format!(
+                    "{:>8} {:>4}  {:<20} {} -> {}",
+                    entry.step,
+                    entry.pc,
+                    format!("{:?}", self.program[entry.pc]),
+                    registers(&entry.before),
+                    registers(&entry.after),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Execute a single instruction, returning `false` if the machine had already halted.
+    pub fn step(&mut self, observers: &mut [&mut dyn Observer]) -> Result<bool, MachineError> {
+        let Some(instruction) = self.program.get(self.pc) else {
+            return Ok(false);
+        };
+        if self.step_limit.is_some_and(|limit| self.steps >= limit) {
+            return Err(MachineError::StepLimit { steps: self.steps });
+        }
+
+        for _ in 0..instruction.cycles() {
+            self.cycle += 1;
+            for observer in observers.iter_mut() {
+                observer.on_cycle(self.cycle, &self.registers);
+            }
+        }
+
+        let before = self.trace.is_some().then(|| self.registers.values.clone());
+        let flow = instruction.execute(&mut self.registers);
+        if let (Some((keep, entries)), Some(before)) = (&mut self.trace, before) {
+            if entries.len() == *keep {
+                entries.pop_front();
+            }
+            if *keep > 0 {
+                entries.push_back(TraceEntry {
+                    step: self.steps,
+                    pc: self.pc,
+                    before,
+                    after: self.registers.values.clone(),
+                });
+            }
+        }
+        self.steps += 1;
+
+        self.pc =
+            match flow {
+                Flow::Next => self.pc + 1,
+                Flow::Jump(offset) => self.pc.checked_add_signed(offset as isize).ok_or(
+                    MachineError::JumpOutOfRange {
+                        pc: self.pc,
+                        offset,
+                    },
+                )?,
+            };
+        Ok(true)
+    }
+
+    /// Execute up to `count` instructions, stopping early at a breakpoint or when halted.
+    pub fn step_n(&mut self, count: usize) -> Result<Stop, MachineError> {
+        for _ in 0..count {
+            if let Some(stop) = self.step_stopping(&mut [])? {
+                return Ok(stop);
+            }
+        }
+        Ok(Stop::Paused)
+    }
+
+    pub fn run(&mut self) -> Result<Stop, MachineError> {
+        self.run_observed(&mut [])
+    }
+
+    /// Run until halted or a breakpoint is reached.
+    pub fn run_observed(
+        &mut self,
+        observers: &mut [&mut dyn Observer],
+    ) -> Result<Stop, MachineError> {
+        loop {
+            if let Some(stop) = self.step_stopping(observers)? {
+                return Ok(stop);
+            }
+        }
+    }
+
+    fn step_stopping(
+        &mut self,
+        observers: &mut [&mut dyn Observer],
+    ) -> Result<Option<Stop>, MachineError> {
+        if !self.step(observers)? || self.is_halted() {
+            Ok(Some(Stop::Halted))
+        } else if self.breakpoints.contains(&self.pc) {
+            Ok(Some(Stop::Breakpoint(self.pc)))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    enum Op {
+        Add(usize, i64),
+        Slow(usize),
+        JumpIfPositive(usize, i64),
+    }
+
+    impl Instruction for Op {
+        fn cycles(&self) -> usize {
+            match self {
+                Op::Slow(_) => 3,
+                _ => 1,
+            }
+        }
+
+        fn execute(&self, registers: &mut Registers) -> Flow {
+            match *self {
+                Op::Add(register, value) => registers[register] += value,
+                Op::Slow(register) => registers[register] *= 2,
+                Op::JumpIfPositive(register, offset) => {
+                    if registers[register] > 0 {
+                        return Flow::Jump(offset);
+                    }
+                }
+            }
+            Flow::Next
+        }
+    }
+
+    #[derive(Default)]
+    struct Samples(Vec<(usize, i64)>);
+
+    impl Observer for Samples {
+        fn on_cycle(&mut self, cycle: usize, registers: &Registers) {
+            self.0.push((cycle, registers[0]));
+        }
+    }
+
+    #[test]
+    fn test_registers() {
+        let mut registers = Registers::new(&["a", "b"]);
+        assert!(registers.set("b", 4));
+        assert!(!registers.set("c", 4));
+        assert_eq!(registers.get("b"), Some(4));
+        assert_eq!(registers.index_of("a"), Some(0));
+        assert_eq!(registers.to_string(), "a=0 b=4");
+    }
+
+    #[test]
+    fn test_run() {
+        // Count register 1 down to zero, adding 2 to register 0 each time.
+        let program = vec![
+            Op::Add(0, 2),
+            Op::Add(1, -1),
+            Op::JumpIfPositive(1, -2),
+            Op::Slow(0),
+        ];
+        let mut registers = Registers::new(&["a", "b"]);
+        registers.set("b", 3);
+        let mut machine = Machine::new(program, registers);
+        let mut samples = Samples::default();
+        machine.run_observed(&mut [&mut samples]).unwrap();
+
+        assert!(machine.is_halted());
+        assert_eq!(machine.registers().get("a"), Some(12));
+        assert_eq!(machine.cycle(), 12);
+        // The slow instruction's result only shows up after its last cycle.
+        assert_eq!(&samples.0[9..], &[(10, 6), (11, 6), (12, 6)]);
+    }
+
+    #[test]
+    fn test_jump_out_of_range() {
+        let mut registers = Registers::new(&["a"]);
+        registers[0] = 1;
+        let mut machine = Machine::new(vec![Op::JumpIfPositive(0, -1)], registers);
+        assert_eq!(
+            machine.run(),
+            Err(MachineError::JumpOutOfRange { pc: 0, offset: -1 })
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_and_steps() {
+        let program = vec![Op::Add(0, 1), Op::Add(0, 1), Op::Add(0, 1)];
+        let mut machine = Machine::new(program, Registers::new(&["a"]));
+        machine.add_breakpoint(1);
+
+        assert_eq!(machine.run(), Ok(Stop::Breakpoint(1)));
+        assert_eq!(machine.registers()[0], 1);
+        assert_eq!(machine.step_n(1), Ok(Stop::Paused));
+        assert_eq!(machine.pc(), 2);
+        assert_eq!(machine.step_n(5), Ok(Stop::Halted));
+        assert_eq!(machine.steps(), 3);
+    }
+
+    #[test]
+    fn test_trace_and_step_limit() {
+        let mut registers = Registers::new(&["a"]);
+        registers[0] = 1;
+        let program = vec![Op::Add(0, 1), Op::JumpIfPositive(0, -1)];
+        let mut machine = Machine::new(program, registers);
+        machine.enable_trace(2);
+        machine.set_step_limit(5);
+
+        assert_eq!(machine.run(), Err(MachineError::StepLimit { steps: 5 }));
+        let trace: Vec<_> = machine.trace().cloned().collect();
+        assert_eq!(
+            trace,
+            vec![
+                TraceEntry {
+                    step: 3,
+                    pc: 1,
+                    before: vec![3],
+                    after: vec![3],
+                },
+                TraceEntry {
+                    step: 4,
+                    pc: 0,
+                    before: vec![3],
+                    after: vec![4],
+                },
+            ]
+        );
+        assert_eq!(
+            machine.dump_trace(),
+            "       3    1  JumpIfPositive(0, -1) a=3 -> a=3\n       4    0  Add(0, 1)            a=3 -> a=4"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use crate::Grid;
+
+/// Height of every glyph, in pixels.
+const HEIGHT: usize = 6;
+/// Width of the cell each letter sits in, counting the blank column after it.
+const CELL: usize = 5;
+
+/// The block letters drawn by puzzles like 2016 day 8 and 2022 day 10. Most are four pixels
+/// wide, `Y` uses the whole five pixel cell.
+const FONT: [(char, [&str; HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Whether the glyph matches the cell at column `left`, where pixels the glyph leaves out must
+/// be dark.
+fn matches<T>(
+    glyph: &[&str; HEIGHT],
+    grid: &Grid<T>,
+    left: usize,
+    lit: &impl Fn(&T) -> bool,
+) -> bool {
+    (0..HEIGHT).all(|y| {
+        let row = glyph[y].as_bytes();
+        (left..(left + CELL).min(grid.width())).all(|x| {
+            let expected = row.get(x - left) == Some(&b'#');
+            lit(&grid[(x, y)]) == expected
+        })
+    })
+}
+
+/// Read the letters off a screen six pixels high with one letter every five columns. Blank
+/// cells read as spaces, dropped from the end, and anything not in the font reads as `?`.
+pub fn read_letters<T>(grid: &Grid<T>, lit: impl Fn(&T) -> bool) -> String {
+    if grid.height() != HEIGHT {
+        return "?".to_string();
+    }
+    let text: String = (0..grid.width())
+        .step_by(CELL)
+        .map(|left| {
+            let blank = (0..HEIGHT)
+                .all(|y| (left..(left + CELL).min(grid.width())).all(|x| !lit(&grid[(x, y)])));
+            if blank {
+                return ' ';
+            }
+            FONT.iter()
+                .find(|(_, glyph)| matches(glyph, grid, left, &lit))
+                .map_or('?', |(letter, _)| *letter)
+        })
+        .collect();
+    text.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(rows: &[&str]) -> Grid<char> {
+        Grid::parse(&rows.join("\n"), Ok).unwrap()
+    }
+
+    #[test]
+    fn test_read_letters() {
+        // The CRT from 2022 day 10.
+        let crt = screen(&[
+            "###...##..#..#.####..##..#....#..#..##..",
+            "#..#.#..#.#..#.#....#..#.#....#..#.#..#.",
+            "#..#.#....####.###..#....#....#..#.#....",
+            "###..#.##.#..#.#....#.##.#....#..#.#.##.",
+            "#....#..#.#..#.#....#..#.#....#..#.#..#.",
+            "#.....###.#..#.#.....###.####..##...###.",
+        ]);
+        assert_eq!(read_letters(&crt, |c| *c == '#'), "PGHFGLUG");
+
+        // 2016 day 8 style, with a five pixel wide letter and blank cells at the end.
+        let screen = screen(&[
+            "#...#.##..####...............",
+            "#...##..#.#..................",
+            ".#.#.#..#.###................",
+            "..#..#..#.#..................",
+            "..#..#..#.#..................",
+            "..#...##..####...............",
+        ]);
+        assert_eq!(read_letters(&screen, |c| *c == '#'), "YOE");
+    }
+
+    #[test]
+    fn test_unknown() {
+        // The example program for 2022 day 10 draws stripes rather than letters.
+        let crt = screen(&[
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ]);
+        assert_eq!(read_letters(&crt, |c| *c == '#'), "????????");
+
+        let short = screen(&["####", "#..."]);
+        assert_eq!(read_letters(&short, |c| *c == '#'), "?");
+    }
+}
@@ -0,0 +1,218 @@
+use std::error::Error;
+use std::fmt;
+
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use nom::{Finish, IResult, Slice};
+use nom_locate::LocatedSpan;
+
+/// Input type for parsers that report [`ParseError`]s.
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+/// A parse failure pointing at a line and column of the puzzle input.
+///
+/// Line and column are both 1-based. The display form repeats the offending line with a caret
+/// under the column so the problem can be spotted without opening the input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: u32,
+    column: usize,
+    expected: String,
+    source_line: String,
+    offset: usize,
+    labelled: bool,
+}
+
+impl ParseError {
+    /// Build an error for parsers that do not use nom, pointing at `column` of the `line`-th
+    /// line of `input`.
+    pub fn in_input(input: &str, line: u32, column: usize, expected: impl Into<String>) -> Self {
+        let source_line = input
+            .lines()
+            .nth(line.saturating_sub(1) as usize)
+            .unwrap_or_default();
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            source_line: source_line.to_string(),
+            offset: 0,
+            labelled: true,
+        }
+    }
+
+    pub fn at(span: Span, expected: impl Into<String>) -> Self {
+        Self {
+            line: span.location_line(),
+            column: span.get_utf8_column(),
+            expected: expected.into(),
+            source_line: String::from_utf8_lossy(span.get_line_beginning())
+                .trim_end_matches('\r')
+                .to_string(),
+            offset: span.location_offset(),
+            labelled: true,
+        }
+    }
+
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    fn unlabelled(span: Span, expected: impl Into<String>) -> Self {
+        Self {
+            labelled: false,
+            ..Self::at(span, expected)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{}", self.source_line)?;
+        write!(f, "{:>width$}", "^", width = self.column.max(1))
+    }
+}
+
+impl Error for ParseError {}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number",
+        ErrorKind::Alpha => "a word",
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace",
+        ErrorKind::Eof => "end of line",
+        ErrorKind::MapRes | ErrorKind::MapOpt => "a valid value",
+        _ => return kind.description().to_lowercase(),
+    }
+    .to_string()
+}
+
+impl<'a> nom::error::ParseError<Span<'a>> for ParseError {
+    fn from_error_kind(input: Span<'a>, kind: ErrorKind) -> Self {
+        Self::unlabelled(input, describe(kind))
+    }
+
+    fn append(_input: Span<'a>, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: Span<'a>, c: char) -> Self {
+        Self::at(input, format!("`{}`", c))
+    }
+
+    /// Keep whichever alternative got further; alternatives failing at the same spot have their
+    /// expectations combined.
+    fn or(self, other: Self) -> Self {
+        if other.offset > self.offset {
+            other
+        } else if other.offset == self.offset && other.expected != self.expected {
+            Self {
+                expected: format!("{} or {}", self.expected, other.expected),
+                labelled: self.labelled && other.labelled,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+}
+
+/// A `context` label replaces the generic description, but only when the labelled parser failed
+/// before consuming anything, so the label still describes the text under the caret.
+impl<'a> ContextError<Span<'a>> for ParseError {
+    fn add_context(input: Span<'a>, context: &'static str, other: Self) -> Self {
+        if !other.labelled && other.offset == input.location_offset() {
+            Self::at(input, context)
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<Span<'a>, E> for ParseError {
+    fn from_external_error(input: Span<'a>, kind: ErrorKind, _e: E) -> Self {
+        Self::unlabelled(input, describe(kind))
+    }
+}
+
+/// Run `parser` over each line of `input`, requiring it to consume the whole line.
+pub fn parse_each_line<'a, O, F>(input: &'a str, mut parser: F) -> Result<Vec<O>, ParseError>
+where
+    F: FnMut(Span<'a>) -> IResult<Span<'a>, O, ParseError>,
+{
+    let span = Span::new(input);
+    let mut offset = 0;
+    let mut results = Vec::new();
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let line_span = span.slice(offset..offset + content.len());
+        offset += line.len();
+
+        let (rest, value) = parser(line_span).finish()?;
+        if !rest.fragment().is_empty() {
+            return Err(ParseError::at(rest, "end of line"));
+        }
+        results.push(value);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::{char, digit1};
+    use nom::combinator::map_res;
+    use nom::error::context;
+    use nom::sequence::separated_pair;
+
+    fn pair(input: Span) -> IResult<Span, (u32, u32), ParseError> {
+        let number = || context("a number", map_res(digit1, |s: Span| s.fragment().parse()));
+        separated_pair(number(), char(','), number())(input)
+    }
+
+    #[test]
+    fn test_parse_each_line() {
+        assert_eq!(
+            parse_each_line("1,2\n30,4\n", pair).unwrap(),
+            vec![(1, 2), (30, 4)]
+        );
+    }
+
+    #[test]
+    fn test_error_location() {
+        let error = parse_each_line("1,2\n30;4\n", pair).unwrap_err();
+        assert_eq!(error.line(), 2);
+        assert_eq!(error.column(), 3);
+        assert_eq!(error.expected(), "`,`");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected `,`\n30;4\n  ^"
+        );
+
+        let error = parse_each_line("1,2\n3,x", pair).unwrap_err();
+        assert_eq!((error.line(), error.column()), (2, 3));
+        assert_eq!(error.expected(), "a number");
+
+        let error = parse_each_line("1,2 \n", pair).unwrap_err();
+        assert_eq!((error.line(), error.column()), (1, 4));
+        assert_eq!(error.expected(), "end of line");
+    }
+
+    #[test]
+    fn test_in_input() {
+        let error = ParseError::in_input("abc\ndef\n", 2, 2, "`x`");
+        assert_eq!(error.to_string(), "line 2, column 2: expected `x`\ndef\n ^");
+    }
+}
@@ -0,0 +1,220 @@
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+
+use crate::Grid;
+
+/// Map `value` out of `0..=max` onto black through white.
+pub fn gray(value: u64, max: u64) -> u8 {
+    if max == 0 {
+        return 0;
+    }
+    (value.min(max) * 255 / max) as u8
+}
+
+/// A grayscale picture, one byte per pixel in row-major order with 0 as black.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Draw each cell of `grid` as a `scale` by `scale` square colored by `shade`.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, shade: impl Fn(&T) -> u8) -> Self {
+        let width = grid.width() * scale;
+        let height = grid.height() * scale;
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in grid.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| std::iter::repeat_n(shade(cell), scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Write a binary (P6) PPM.
+    pub fn write_ppm(&self, mut out: impl Write) -> Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let rgb: Vec<u8> = self.pixels.iter().flat_map(|&v| [v, v, v]).collect();
+        out.write_all(&rgb)?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, out: impl Write) -> Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        Ok(())
+    }
+
+    /// Save as a PNG or a PPM, going by the extension of `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let out = BufWriter::new(
+            File::create(path).with_context(|| format!("creating {}", path.display()))?,
+        );
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("png") => self.write_png(out),
+            Some("ppm") => self.write_ppm(out),
+            _ => bail!("{}: expected a .png or .ppm file", path.display()),
+        }
+    }
+}
+
+/// An animated GIF, written a frame at a time and looping forever.
+pub struct Animation<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    /// Time each frame is shown, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> Animation<W> {
+    pub fn new(out: W, width: usize, height: usize, delay: u16) -> Result<Self> {
+        let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => bail!("{}x{} is too large for a GIF", width, height),
+        };
+        // Every pixel value is its own palette entry.
+        let palette: Vec<u8> = (0..=255).flat_map(|v| [v, v, v]).collect();
+        let mut encoder = gif::Encoder::new(out, width, height, &palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        Ok(Self {
+            encoder,
+            width,
+            height,
+            delay,
+        })
+    }
+
+    pub fn add(&mut self, image: &Image) -> Result<()> {
+        if (image.width, image.height) != (self.width as usize, self.height as usize) {
+            bail!(
+                "a {}x{} frame in a {}x{} animation",
+                image.width,
+                image.height,
+                self.width,
+                self.height
+            );
+        }
+        let mut frame =
+            gif::Frame::from_indexed_pixels(self.width, self.height, image.pixels.as_slice(), None);
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+        Ok(())
+    }
+}
+
+impl Animation<BufWriter<File>> {
+    pub fn create(path: impl AsRef<Path>, width: usize, height: usize, delay: u16) -> Result<Self> {
+        let path = path.as_ref();
+        let out = File::create(path).with_context(|| format!("creating {}", path.display()))?;
+        Self::new(BufWriter::new(out), width, height, delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Grid<bool> {
+        let mut grid = Grid::new(3, 2, false);
+        grid[(0, 0)] = true;
+        grid[(2, 0)] = true;
+        grid[(1, 1)] = true;
+        grid
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let image = Image::from_grid(&checkerboard(), 2, |lit| if *lit { 255 } else { 0 });
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(&image.pixels()[..6], &[255, 255, 0, 0, 255, 255]);
+        assert_eq!(&image.pixels()[6..12], &[255, 255, 0, 0, 255, 255]);
+        assert_eq!(&image.pixels()[12..18], &[0, 0, 255, 255, 0, 0]);
+
+        assert_eq!(gray(0, 0), 0);
+        assert_eq!(gray(5, 10), 127);
+        assert_eq!(gray(12, 10), 255);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let image = Image::from_grid(&checkerboard(), 1, |lit| *lit as u8 * 200);
+        let mut out = Vec::new();
+        image.write_ppm(&mut out).unwrap();
+        assert!(out.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(&out[11..17], &[200, 200, 200, 0, 0, 0]);
+        assert_eq!(out.len(), 11 + 3 * 6);
+    }
+
+    #[test]
+    fn test_png() {
+        let image = Image::from_grid(&checkerboard(), 1, |lit| *lit as u8 * 200);
+        let mut out = Vec::new();
+        image.write_png(&mut out).unwrap();
+
+        let mut reader = png::Decoder::new(out.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(&pixels[..info.buffer_size()], image.pixels());
+    }
+
+    #[test]
+    fn test_gif() {
+        let mut grid = checkerboard();
+        let mut out = Vec::new();
+        {
+            let mut animation = Animation::new(&mut out, 3, 2, 5).unwrap();
+            for _ in 0..3 {
+                animation
+                    .add(&Image::from_grid(&grid, 1, |lit| *lit as u8 * 255))
+                    .unwrap();
+                grid.rotate_row(0, 1);
+            }
+            let wrong_size = Image::from_grid(&grid, 2, |_| 0);
+            assert!(animation.add(&wrong_size).is_err());
+        }
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(out.as_slice()).unwrap();
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 5);
+            frames.push(frame.buffer.to_vec());
+        }
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], vec![255, 0, 255, 0, 255, 0]);
+        assert_eq!(frames[1], vec![255, 255, 0, 0, 255, 0]);
+    }
+}
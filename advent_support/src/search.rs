@@ -0,0 +1,347 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A puzzle searched for its cheapest way from a start state to a goal.
+pub trait Problem {
+    type State;
+    /// Costs start from `Default::default()` and add up along a path.
+    type Cost: Copy + Ord + Default + Add<Output = Self::Cost>;
+
+    /// The states one move away, each with the cost of that move.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, Self::Cost)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound on the cost from `state` to the nearest goal, used by [`a_star`]. It must
+    /// never overestimate, or the answer may not be the cheapest.
+    fn heuristic(&self, _state: &Self::State) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// Decides, as each state comes off the queue, whether it is worth expanding. This is where
+/// a search drops states it has seen before or that are dominated by ones it already has.
+pub trait Prune<S, C> {
+    /// Return `false` to drop `state`, reached for `cost`.
+    fn keep(&mut self, state: &S, cost: C) -> bool;
+}
+
+impl<S, C, F: FnMut(&S, C) -> bool> Prune<S, C> for F {
+    fn keep(&mut self, state: &S, cost: C) -> bool {
+        self(state, cost)
+    }
+}
+
+/// Expands every state that reaches the front of the queue.
+pub struct KeepAll;
+
+impl<S, C> Prune<S, C> for KeepAll {
+    fn keep(&mut self, _state: &S, _cost: C) -> bool {
+        true
+    }
+}
+
+/// Expands each state only the first time it comes off the queue, which is also the cheapest.
+#[derive(Debug)]
+pub struct Visited<S> {
+    seen: HashSet<S>,
+}
+
+impl<S> Default for Visited<S> {
+    fn default() -> Self {
+        Self {
+            seen: HashSet::new(),
+        }
+    }
+}
+
+impl<S: Clone + Eq + Hash, C> Prune<S, C> for Visited<S> {
+    fn keep(&mut self, state: &S, _cost: C) -> bool {
+        self.seen.insert(state.clone())
+    }
+}
+
+/// The way to a goal and what it cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Found<S, C> {
+    /// Every state from the start to the goal, both included.
+    pub path: Vec<S>,
+    pub cost: C,
+}
+
+impl<S, C> Found<S, C> {
+    pub fn goal(&self) -> &S {
+        self.path.last().expect("a path has at least the start")
+    }
+}
+
+/// Every state queued so far, each linked to the state it was reached from.
+struct Parents<S> {
+    nodes: Vec<(S, Option<usize>)>,
+}
+
+impl<S> Parents<S> {
+    fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    fn add(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((state, parent));
+        self.nodes.len() - 1
+    }
+
+    fn state(&self, node: usize) -> &S {
+        &self.nodes[node].0
+    }
+
+    /// The states leading up to `node`, starting from the one without a parent.
+    fn into_path(self, node: usize) -> Vec<S> {
+        let mut chain = vec![node];
+        while let Some(parent) = self.nodes[*chain.last().unwrap()].1 {
+            chain.push(parent);
+        }
+        let mut states: Vec<Option<S>> = self.nodes.into_iter().map(|(s, _)| Some(s)).collect();
+        chain
+            .into_iter()
+            .rev()
+            .map(|node| states[node].take().unwrap())
+            .collect()
+    }
+}
+
+/// A queued state. Ordered so that `BinaryHeap` pops the lowest priority first, and among
+/// equal priorities the one queued first.
+struct Entry<C> {
+    priority: C,
+    order: usize,
+    cost: C,
+    node: usize,
+}
+
+impl<C: Ord> Ord for Entry<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl<C: Ord> PartialOrd for Entry<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Entry<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Entry<C> {}
+
+fn best_first<P: Problem>(
+    problem: &P,
+    start: P::State,
+    mut prune: impl Prune<P::State, P::Cost>,
+    heuristic: impl Fn(&P::State) -> P::Cost,
+) -> Option<Found<P::State, P::Cost>> {
+    let mut parents = Parents::new();
+    let mut queue = BinaryHeap::new();
+    let mut order = 0..;
+    queue.push(Entry {
+        priority: heuristic(&start),
+        order: order.next().unwrap(),
+        cost: P::Cost::default(),
+        node: parents.add(start, None),
+    });
+
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        let state = parents.state(node);
+        if problem.is_goal(state) {
+            return Some(Found {
+                path: parents.into_path(node),
+                cost,
+            });
+        }
+        if !prune.keep(state, cost) {
+            continue;
+        }
+        for (next, step) in problem.successors(state) {
+            let cost = cost + step;
+            queue.push(Entry {
+                priority: cost + heuristic(&next),
+                order: order.next().unwrap(),
+                cost,
+                node: parents.add(next, Some(node)),
+            });
+        }
+    }
+
+    None
+}
+
+/// The cheapest goal, expanding states in order of the cost of reaching them.
+pub fn dijkstra<P: Problem>(
+    problem: &P,
+    start: P::State,
+    prune: impl Prune<P::State, P::Cost>,
+) -> Option<Found<P::State, P::Cost>> {
+    best_first(problem, start, prune, |_| P::Cost::default())
+}
+
+/// The cheapest goal, expanding states in order of their cost plus [`Problem::heuristic`].
+pub fn a_star<P: Problem>(
+    problem: &P,
+    start: P::State,
+    prune: impl Prune<P::State, P::Cost>,
+) -> Option<Found<P::State, P::Cost>> {
+    best_first(problem, start, prune, |state| problem.heuristic(state))
+}
+
+/// The goal fewest moves away, ignoring what the moves cost. `cost` in the result and in the
+/// calls to `prune` is the number of moves.
+pub fn breadth_first<P: Problem>(
+    problem: &P,
+    start: P::State,
+    mut prune: impl Prune<P::State, usize>,
+) -> Option<Found<P::State, usize>> {
+    let mut parents = Parents::new();
+    let mut queue = VecDeque::new();
+    queue.push_back((parents.add(start, None), 0));
+
+    while let Some((node, moves)) = queue.pop_front() {
+        let state = parents.state(node);
+        if problem.is_goal(state) {
+            return Some(Found {
+                path: parents.into_path(node),
+                cost: moves,
+            });
+        }
+        if !prune.keep(state, moves) {
+            continue;
+        }
+        for (next, _) in problem.successors(state) {
+            queue.push_back((parents.add(next, Some(node)), moves + 1));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    /// Walking a maze where stepping onto a digit costs that much and `#` is a wall.
+    struct Maze {
+        grid: Grid<char>,
+        goal: (usize, usize),
+    }
+
+    impl Maze {
+        fn new(rows: &[&str]) -> Self {
+            let grid = Grid::parse(&rows.join("\n"), Ok).unwrap();
+            let goal = (grid.width() - 1, grid.height() - 1);
+            Self { grid, goal }
+        }
+    }
+
+    impl Problem for Maze {
+        type State = (usize, usize);
+        type Cost = u32;
+
+        fn successors(&self, &(x, y): &(usize, usize)) -> Vec<((usize, usize), u32)> {
+            let mut next = Vec::new();
+            if x > 0 {
+                next.push((x - 1, y));
+            }
+            if y > 0 {
+                next.push((x, y - 1));
+            }
+            if x + 1 < self.grid.width() {
+                next.push((x + 1, y));
+            }
+            if y + 1 < self.grid.height() {
+                next.push((x, y + 1));
+            }
+            next.into_iter()
+                .filter_map(|position| {
+                    self.grid[position]
+                        .to_digit(10)
+                        .map(|cost| (position, cost))
+                })
+                .collect()
+        }
+
+        fn is_goal(&self, state: &(usize, usize)) -> bool {
+            *state == self.goal
+        }
+
+        fn heuristic(&self, &(x, y): &(usize, usize)) -> u32 {
+            // Every step costs at least one.
+            ((self.goal.0 - x) + (self.goal.1 - y)) as u32
+        }
+    }
+
+    #[test]
+    fn test_cheapest_path() {
+        let maze = Maze::new(&["1191", "1#11", "1911", "1111"]);
+        let found = dijkstra(&maze, (0, 0), Visited::default()).unwrap();
+        assert_eq!(
+            found,
+            Found {
+                path: vec![(0, 0), (0, 1), (0, 2), (0, 3), (1, 3), (2, 3), (3, 3)],
+                cost: 6
+            }
+        );
+        assert_eq!(found.goal(), &(3, 3));
+        assert_eq!(a_star(&maze, (0, 0), Visited::default()).unwrap().cost, 6);
+
+        // Every route goes through a 9, which breadth-first counts as one more move.
+        let maze = Maze::new(&["1911", "1#11", "9111", "1111"]);
+        assert_eq!(
+            dijkstra(&maze, (0, 0), Visited::default()).unwrap().cost,
+            14
+        );
+        assert_eq!(
+            breadth_first(&maze, (0, 0), Visited::default())
+                .unwrap()
+                .cost,
+            6
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let maze = Maze::new(&["11", "1#", "#1"]);
+        assert_eq!(dijkstra(&maze, (0, 0), Visited::default()), None);
+        assert_eq!(a_star(&maze, (0, 0), Visited::default()), None);
+        assert_eq!(breadth_first(&maze, (0, 0), Visited::default()), None);
+    }
+
+    #[test]
+    fn test_prune() {
+        let maze = Maze::new(&["111", "111", "111"]);
+        // Without a visited set the search still ends, revisiting cells along the way.
+        assert_eq!(dijkstra(&maze, (0, 0), KeepAll).unwrap().cost, 4);
+
+        // A hook that also refuses the middle column cuts the left side off from the goal.
+        let maze = Maze::new(&["111", "111", "1#1"]);
+        let mut visited = Visited::default();
+        let mut refused = HashSet::new();
+        let found = dijkstra(&maze, (0, 0), |state: &(usize, usize), cost| {
+            if state.0 == 1 {
+                refused.insert(*state);
+                return false;
+            }
+            visited.keep(state, cost)
+        });
+        assert_eq!(found, None);
+        assert_eq!(refused, IntoIterator::into_iter([(1, 0), (1, 1)]).collect());
+    }
+}
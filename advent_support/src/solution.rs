@@ -0,0 +1,31 @@
+use anyhow::{bail, Result};
+
+use crate::read_input_string;
+
+/// A single day's puzzle. Both parts receive the full, unparsed input.
+pub trait Solution {
+    fn part1(&self, input: &str) -> Result<String>;
+
+    fn part2(&self, _input: &str) -> Result<String> {
+        bail!("part 2 is not implemented")
+    }
+}
+
+/// Read the puzzle input from stdin and print the answer to each part.
+pub fn run<S: Solution>(solution: &S) -> Result<()> {
+    let input = read_input_string()?;
+
+    print_answer(1, &solution.part1(&input)?);
+    print_answer(2, &solution.part2(&input)?);
+
+    Ok(())
+}
+
+/// Multi-line answers (rendered screens and the like) start on their own line.
+pub fn print_answer(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        println!("part {}:\n{}", part, answer);
+    } else {
+        println!("part {}: {}", part, answer);
+    }
+}
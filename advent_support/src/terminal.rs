@@ -0,0 +1,115 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+/// Frame delay used when `--animate` is given without `--delay`.
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// Look for `--animate`, with an optional `--delay MS`, among the command line arguments.
+/// Returns the delay between frames when animating.
+pub fn animation_args(args: impl IntoIterator<Item = String>) -> Result<Option<Duration>> {
+    let mut animate = false;
+    let mut delay = DEFAULT_DELAY;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--animate" => animate = true,
+            "--delay" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--delay needs a value"))?;
+                let millis = value
+                    .parse()
+                    .with_context(|| format!("invalid delay: {}", value))?;
+                delay = Duration::from_millis(millis);
+            }
+            _ => bail!("unknown option: {}", arg),
+        }
+    }
+
+    Ok(animate.then_some(delay))
+}
+
+/// Draws frames over one another in the terminal with ANSI escape codes.
+pub struct TerminalAnimation<W: Write> {
+    out: W,
+    delay: Duration,
+    started: bool,
+}
+
+impl TerminalAnimation<io::Stdout> {
+    pub fn stdout(delay: Duration) -> Self {
+        Self::new(io::stdout(), delay)
+    }
+}
+
+impl<W: Write> TerminalAnimation<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        Self {
+            out,
+            delay,
+            started: false,
+        }
+    }
+
+    /// Replace whatever was drawn last with `picture`, then wait out the frame delay.
+    pub fn frame(&mut self, picture: &str) -> Result<()> {
+        if !self.started {
+            // Clear the screen once, after that only overwrite it.
+            write!(self.out, "\x1b[2J")?;
+            self.started = true;
+        }
+        write!(self.out, "\x1b[H")?;
+        for line in picture.lines() {
+            // Erase the rest of each line in case the last frame was wider.
+            writeln!(self.out, "{}\x1b[K", line)?;
+        }
+        write!(self.out, "\x1b[J")?;
+        self.out.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_animation_args() {
+        assert_eq!(animation_args(args(&[])).unwrap(), None);
+        assert_eq!(
+            animation_args(args(&["--animate"])).unwrap(),
+            Some(DEFAULT_DELAY)
+        );
+        assert_eq!(
+            animation_args(args(&["--delay", "5", "--animate"])).unwrap(),
+            Some(Duration::from_millis(5))
+        );
+        assert!(animation_args(args(&["--animate", "--delay"])).is_err());
+        assert!(animation_args(args(&["--fast"])).is_err());
+    }
+
+    #[test]
+    fn test_frames() {
+        let mut animation = TerminalAnimation::new(Vec::new(), Duration::ZERO);
+        animation.frame("ab\ncd").unwrap();
+        animation.frame("x").unwrap();
+        let out = String::from_utf8(animation.into_inner()).unwrap();
+        assert_eq!(
+            out,
+            "\x1b[2J\x1b[Hab\x1b[K\ncd\x1b[K\n\x1b[J\x1b[Hx\x1b[K\n\x1b[J"
+        );
+    }
+}
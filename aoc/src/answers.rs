@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// The recorded answers for one day, as found in `<year>/answers.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DayAnswers {
+    /// Puzzle input relative to the year directory, defaults to `inputs/<day>`.
+    input: Option<String>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+pub struct YearAnswers {
+    year: u16,
+    directory: PathBuf,
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl YearAnswers {
+    /// Load `<root>/<year>/answers.toml`. A missing file means nothing is recorded yet.
+    pub fn load(root: &Path, year: u16) -> Result<Self> {
+        let directory = root.join(year.to_string());
+        let path = directory.join("answers.toml");
+
+        let days = if path.exists() {
+            let text =
+                fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+            parse(&text).with_context(|| format!("parsing {}", path.display()))?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Self {
+            year,
+            directory,
+            days,
+        })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self, day: u8) -> Option<&DayAnswers> {
+        self.days.get(&day)
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        match self.day(day).and_then(|answers| answers.input.as_ref()) {
+            Some(input) => self.directory.join(input),
+            None => self.directory.join("inputs").join(day.to_string()),
+        }
+    }
+}
+
+fn parse(text: &str) -> Result<BTreeMap<u8, DayAnswers>> {
+    let raw: BTreeMap<String, DayAnswers> = toml::from_str(text)?;
+    raw.into_iter()
+        .map(|(day, answers)| {
+            let day = day
+                .parse()
+                .with_context(|| format!("invalid day: {}", day))?;
+            Ok((day, answers))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let days = parse(
+            r#"
+[1]
+part1 = "280"
+
+[22]
+input = "rust/day22/data.txt"
+part1 = "953"
+part2 = """
+##
+.#"""
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(days[&1].part(1), Some("280"));
+        assert_eq!(days[&1].part(2), None);
+        assert_eq!(days[&22].input.as_deref(), Some("rust/day22/data.txt"));
+        assert_eq!(days[&22].part(2), Some("##\n.#"));
+    }
+
+    #[test]
+    fn test_rejects_bad_day() {
+        assert!(parse("[first]\npart1 = \"1\"\n").is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+mod answers;
+mod registry;
+mod verify;
+
+const USAGE: &str = "usage: aoc run <year> <day> [--part N] [--variant NAME] [--input PATH]
+       aoc verify [<year>...] [--root DIR]
+       aoc list";
+
+struct RunArgs {
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    /// Empty for the day's main solution.
+    variant: String,
+    input: Option<String>,
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs> {
+    let mut positional = Vec::new();
+    let mut part = None;
+    let mut variant = String::new();
+    let mut input = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                let value = args.next().ok_or_else(|| anyhow!("--part needs a value"))?;
+                part = Some(
+                    value
+                        .parse()
+                        .with_context(|| format!("invalid part: {}", value))?,
+                );
+            }
+            "--variant" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--variant needs a name"))?;
+                variant = value.clone();
+            }
+            "--input" => {
+                let value = args.next().ok_or_else(|| anyhow!("--input needs a path"))?;
+                input = Some(value.clone());
+            }
+            _ if arg.starts_with("--") => bail!("unknown option: {}\n{}", arg, USAGE),
+            _ => positional.push(arg),
+        }
+    }
+
+    match positional.as_slice() {
+        [year, day] => Ok(RunArgs {
+            year: year
+                .parse()
+                .with_context(|| format!("invalid year: {}", year))?,
+            day: day
+                .parse()
+                .with_context(|| format!("invalid day: {}", day))?,
+            part,
+            variant,
+            input,
+        }),
+        _ => bail!(USAGE),
+    }
+}
+
+/// Without `--input` the puzzle input is read from stdin.
+fn load_input(path: Option<&str>) -> Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path).with_context(|| format!("reading {}", path)),
+        None => advent_support::read_input_string(),
+    }
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let args = parse_run_args(args)?;
+    let registry = registry::registry();
+    let day = if args.variant.is_empty() {
+        format!("{} day {}", args.year, args.day)
+    } else {
+        format!("{} day {} ({})", args.year, args.day, args.variant)
+    };
+
+    let parts: Vec<_> = registry
+        .iter()
+        .filter(|((year, day, variant, part), _)| {
+            (*year, *day, *variant) == (args.year, args.day, args.variant.as_str())
+                && args.part.is_none_or(|wanted| wanted == *part)
+        })
+        .map(|((_, _, _, part), solver)| (*part, solver))
+        .collect();
+    if parts.is_empty() {
+        match args.part {
+            Some(part) => bail!("no solution for {} part {}", day, part),
+            None => bail!("no solution for {}", day),
+        }
+    }
+
+    let input = load_input(args.input.as_deref())?;
+    for (part, solver) in parts {
+        advent_support::print_answer(part, &solver(&input)?);
+    }
+
+    Ok(())
+}
+
+/// `aoc verify` looks for `<year>/answers.toml` under `--root`, defaulting to this checkout.
+fn verify(args: &[String]) -> Result<()> {
+    let mut root = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    let mut years = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => {
+                root = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--root needs a path"))?
+                    .into();
+            }
+            _ if arg.starts_with("--") => bail!("unknown option: {}\n{}", arg, USAGE),
+            _ => years.push(
+                arg.parse()
+                    .with_context(|| format!("invalid year: {}", arg))?,
+            ),
+        }
+    }
+
+    let registry = registry::registry();
+    if !verify::verify(&root, &registry, &years)? {
+        process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn list() {
+    for (year, day, variant, part) in registry::registry().keys() {
+        if variant.is_empty() {
+            println!("{} {} {}", year, day, part);
+        } else {
+            println!("{} {} {} {}", year, day, part, variant);
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("list") => {
+            list();
+            Ok(())
+        }
+        _ => bail!(USAGE),
+    }
+}
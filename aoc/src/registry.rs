@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use advent_support::Solution;
+use anyhow::Result;
+
+/// (year, day, variant, part). A day's main solution has the empty variant; alternative
+/// solutions to the same puzzle are registered under a name, like `nom`.
+pub type Key = (u16, u8, &'static str, u8);
+
+pub type Part = fn(&str) -> Result<String>;
+
+macro_rules! register {
+    ($registry:ident, $year:literal, $day:literal, $solution:expr) => {
+        register!($registry, $year, $day, $solution, [1, 2])
+    };
+    ($registry:ident, $year:literal, $day:literal, $solution:expr, [$($part:tt),+]) => {
+        register!($registry, $year, $day, "", $solution, [$($part),+])
+    };
+    ($registry:ident, $year:literal, $day:literal, $variant:literal, $solution:expr) => {
+        register!($registry, $year, $day, $variant, $solution, [1, 2])
+    };
+    ($registry:ident, $year:literal, $day:literal, $variant:literal, $solution:expr, [$($part:tt),+]) => {
+        $(register!(@part $registry, $year, $day, $variant, $solution, $part);)+
+    };
+    (@part $registry:ident, $year:literal, $day:literal, $variant:literal, $solution:expr, 1) => {
+        $registry.insert(($year, $day, $variant, 1), (|input| $solution.part1(input)) as Part);
+    };
+    (@part $registry:ident, $year:literal, $day:literal, $variant:literal, $solution:expr, 2) => {
+        $registry.insert(($year, $day, $variant, 2), (|input| $solution.part2(input)) as Part);
+    };
+}
+
+pub fn registry() -> BTreeMap<Key, Part> {
+    let mut registry = BTreeMap::new();
+
+    register!(registry, 2015, 1, aoc2015_day1::Day1);
+    register!(registry, 2015, 2, aoc2015_day2::Day2);
+    register!(registry, 2015, 3, aoc2015_day3::Day3);
+    register!(registry, 2015, 6, aoc2015_day6::Day6);
+    register!(registry, 2015, 7, aoc2015_day7::Day7);
+    register!(registry, 2015, 15, aoc2015_day15::Day15);
+    register!(registry, 2015, 16, aoc2015_day16::Day16);
+    register!(registry, 2015, 17, aoc2015_day17::Day17);
+    register!(registry, 2015, 21, aoc2015_day21::Day21);
+    register!(registry, 2015, 22, aoc2015_day22::Day22);
+    register!(registry, 2015, 23, aoc2015_day23::Day23);
+    register!(registry, 2015, 24, aoc2015_day24::Day24);
+    register!(registry, 2015, 25, aoc2015_day25::Day25, [1]);
+
+    register!(registry, 2016, 1, aoc2016_day1::Day1);
+    register!(registry, 2016, 2, aoc2016_day2::Day2);
+    register!(registry, 2016, 3, aoc2016_day3::Day3);
+    register!(registry, 2016, 4, aoc2016_day4::Day4);
+    register!(registry, 2016, 5, aoc2016_day5::Day5);
+    register!(registry, 2016, 6, aoc2016_day6::Day6);
+    register!(registry, 2016, 7, aoc2016_day7::Day7);
+    register!(registry, 2016, 8, aoc2016_day8::Day8);
+    register!(registry, 2016, 9, aoc2016_day9::Day9);
+
+    register!(registry, 2019, 2, aoc2019_intcode::Day2);
+    register!(registry, 2019, 5, aoc2019_intcode::Day5);
+    register!(registry, 2019, 7, aoc2019_intcode::Day7);
+
+    register!(registry, 2020, 1, aoc2020_day1::Day1);
+    register!(registry, 2020, 2, aoc2020_day2::Day2);
+    register!(registry, 2020, 3, aoc2020_day3::Day3);
+    register!(registry, 2020, 5, aoc2020_day5::Day5);
+    register!(registry, 2020, 7, aoc2020_day7::Day7, [1]);
+
+    register!(registry, 2021, 1, aoc2021_day1::Day1);
+    register!(registry, 2021, 2, aoc2021_day2::Day2);
+    register!(registry, 2021, 3, aoc2021_day3::Day3, [1]);
+
+    register!(registry, 2022, 1, aoc2022_day1::Day1);
+    register!(registry, 2022, 2, aoc2022_day2::Day2);
+    register!(registry, 2022, 3, aoc2022_day3::Day3);
+    register!(registry, 2022, 4, aoc2022_day4::Day4);
+    register!(registry, 2022, 5, aoc2022_day5::Day5);
+    register!(registry, 2022, 6, aoc2022_day6::Day6);
+    register!(registry, 2022, 7, aoc2022_day7::Day7);
+    register!(registry, 2022, 8, aoc2022_day8::Day8);
+    register!(registry, 2022, 9, aoc2022_day9::Day9);
+    register!(registry, 2022, 10, aoc2022_day10::Day10);
+
+    register!(registry, 2023, 2, aoc2023_day2::Day2);
+    register!(registry, 2023, 3, aoc2023_day3::Day3);
+    register!(registry, 2023, 3, "nom", aoc2023_day3_nom::Day3);
+
+    registry
+}
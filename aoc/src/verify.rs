@@ -0,0 +1,168 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::answers::YearAnswers;
+use crate::registry::{Key, Part};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Changed {
+        expected: String,
+        actual: String,
+    },
+    Fail(String),
+    /// Nothing recorded for this part, so there is nothing to compare against.
+    Unrecorded,
+}
+
+fn run_part(solver: Part, input: &str) -> Result<String> {
+    panic::catch_unwind(AssertUnwindSafe(|| solver(input))).map_err(|_| anyhow!("panicked"))?
+}
+
+pub fn check(solver: Part, input: &str, expected: Option<&str>) -> Outcome {
+    match (run_part(solver, input), expected) {
+        (Err(error), _) => Outcome::Fail(error.to_string()),
+        (Ok(_), None) => Outcome::Unrecorded,
+        (Ok(actual), Some(expected)) if actual.trim_end() == expected.trim_end() => Outcome::Pass,
+        (Ok(actual), Some(expected)) => Outcome::Changed {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::Pass => "pass".to_string(),
+        Outcome::Unrecorded => "unrecorded".to_string(),
+        Outcome::Fail(error) => format!("fail ({})", error),
+        Outcome::Changed { expected, actual }
+            if expected.contains('\n') || actual.contains('\n') =>
+        {
+            format!("changed\n  expected:\n{}\n  got:\n{}", expected, actual)
+        }
+        Outcome::Changed { expected, actual } => {
+            format!("changed (expected {}, got {})", expected, actual)
+        }
+    }
+}
+
+/// Run every registered solution for `years` (all years when empty) and compare each answer with
+/// the one recorded in the year's `answers.toml`. Returns false if anything failed or changed.
+pub fn verify<'a, I>(root: &Path, registry: I, years: &[u16]) -> Result<bool>
+where
+    I: IntoIterator<Item = (&'a Key, &'a Part)>,
+{
+    let mut parts: Vec<(Key, Part)> = registry
+        .into_iter()
+        .filter(|((year, _, _, _), _)| years.is_empty() || years.contains(year))
+        .map(|(key, part)| (*key, *part))
+        .collect();
+    parts.sort_by_key(|(key, _)| *key);
+
+    let days: BTreeSet<(u16, u8)> = parts
+        .iter()
+        .map(|((year, day, _, _), _)| (*year, *day))
+        .collect();
+
+    let mut answers: Option<YearAnswers> = None;
+    let (mut passed, mut failed, mut changed, mut skipped) = (0, 0, 0, 0);
+
+    for (year, day) in days {
+        if answers
+            .as_ref()
+            .is_none_or(|answers| answers.year() != year)
+        {
+            answers = Some(YearAnswers::load(root, year)?);
+        }
+        let answers = answers.as_ref().expect("answers were just loaded");
+
+        let recorded = answers.day(day);
+        let input_path = answers.input_path(day);
+        let input = match (recorded, fs::read_to_string(&input_path)) {
+            (Some(_), Ok(input)) => input,
+            (Some(_), Err(error)) => {
+                println!(
+                    "{} day {:>2}: fail ({}: {})",
+                    year,
+                    day,
+                    input_path.display(),
+                    error
+                );
+                failed += 1;
+                continue;
+            }
+            (None, _) => {
+                println!("{} day {:>2}: unrecorded", year, day);
+                skipped += 1;
+                continue;
+            }
+        };
+
+        for ((_, _, variant, part), solver) in parts
+            .iter()
+            .filter(|((y, d, _, _), _)| (*y, *d) == (year, day))
+        {
+            // Other ways of solving a day are held to the same answers.
+            let outcome = check(*solver, &input, recorded.and_then(|r| r.part(*part)));
+            let variant = if variant.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", variant)
+            };
+            println!(
+                "{} day {:>2} part {}{}: {}",
+                year,
+                day,
+                part,
+                variant,
+                describe(&outcome)
+            );
+            match outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Changed { .. } => changed += 1,
+                Outcome::Fail(_) => failed += 1,
+                Outcome::Unrecorded => skipped += 1,
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} changed, {} failed, {} unrecorded",
+        passed, changed, failed, skipped
+    );
+
+    Ok(changed == 0 && failed == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn echo(input: &str) -> Result<String> {
+        Ok(input.trim().to_string())
+    }
+
+    fn broken(_input: &str) -> Result<String> {
+        panic!("oops")
+    }
+
+    #[test]
+    fn test_outcomes() {
+        assert_eq!(check(echo, "42\n", Some("42")), Outcome::Pass);
+        assert_eq!(check(echo, "42\n", None), Outcome::Unrecorded);
+        assert_eq!(
+            check(echo, "41\n", Some("42")),
+            Outcome::Changed {
+                expected: "42".to_string(),
+                actual: "41".to_string()
+            }
+        );
+        assert!(matches!(check(broken, "", Some("42")), Outcome::Fail(_)));
+    }
+}